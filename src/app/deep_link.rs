@@ -0,0 +1,68 @@
+use leptos::*;
+use leptos_router::{use_location, use_navigate, use_query_map, NavigateOptions};
+
+// a page's state can be shared with query parameters:
+//   open: comma separated windows to open and bring to the front (by id, minus the "-win")
+//   tab:  the tab to show in the opened window(s)
+//   file: the document to show in the file viewer (it has to be linked from the page)
+
+// how a window is named in links
+pub fn link_name(id: &str) -> &str {
+    id.strip_suffix("-win").unwrap_or(id)
+}
+
+pub fn param(key: &str) -> Option<String> {
+    use_query_map().with_untracked(|q| q.get(key).cloned())
+}
+
+// the tab asked for (if any) when the url opens the window with `id`. it follows the url,
+// so links to the page that's already open (like the start menu's) work too
+pub fn window_link(id: &str) -> Memo<Option<Option<String>>> {
+    let query = use_query_map();
+    let name = link_name(id).to_string();
+    create_memo(move |_| {
+        query.with(|q| {
+            let opened = q
+                .get("open")
+                .is_some_and(|open| open.split(',').any(|w| w == name));
+            opened.then(|| q.get("tab").cloned())
+        })
+    })
+}
+
+// whether the url shows the document `src` in a file viewer
+pub fn file_link(src: &'static str) -> Memo<bool> {
+    let query = use_query_map();
+    create_memo(move |_| query.with(|q| q.get("file").is_some_and(|file| file == src)))
+}
+
+// rewrites the current url's query parameters (`None` removes one) without adding a history entry
+pub fn set_params(params: &[(&str, Option<String>)]) {
+    let location = use_location();
+    let before = location.query.get_untracked();
+    let mut query = before.clone();
+    for (key, value) in params {
+        match value {
+            Some(value) => query.insert(key.to_string(), value.clone()),
+            None => query.remove(key),
+        };
+    }
+    if query == before {
+        return;
+    }
+
+    let url = format!(
+        "{}{}{}",
+        location.pathname.get_untracked(),
+        query.to_query_string(),
+        location.hash.get_untracked()
+    );
+    use_navigate()(
+        &url,
+        NavigateOptions {
+            replace: true,
+            scroll: false,
+            ..Default::default()
+        },
+    );
+}
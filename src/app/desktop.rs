@@ -0,0 +1,931 @@
+use crate::app::{tabs::TabSet, title::WindowTitle, TITLEBAR_HEIGHT};
+use leptos::{ev::PointerEvent, *};
+use leptos_use::{use_document, use_event_listener, use_media_query};
+
+// width of a window's border
+pub const WINDOW_BORDER: i32 = 5;
+// how close (in px) an edge has to get to another one before it snaps
+const SNAP_DISTANCE: i32 = 12;
+// how close (in px) the pointer has to get to the side of the screen to tile a window
+const TILE_EDGE: i32 = 8;
+// space left around and between windows when arranging them
+const ARRANGE_GAP: i32 = 20;
+// how far each window is shifted from the previous one when cascading
+const CASCADE_STEP: i32 = 40;
+// how much of a window's titlebar has to stay on screen
+const MIN_VISIBLE: i32 = 100;
+// z-index band for "always on top" windows (still below expanded windows and the footer)
+const ON_TOP_Z: usize = 1024;
+// how many workspaces each page has
+pub const WORKSPACES: usize = 4;
+// how far (in px) the pointer has to move on empty space before a selection band is drawn
+const BAND_THRESHOLD: i32 = 4;
+// keep in sync with the narrow breakpoint in main.scss
+const NARROW_QUERY: &str = "(max-width: 900px)";
+
+#[derive(Copy, Clone, PartialEq)]
+pub struct Rect {
+    pub x: i32,
+    pub y: i32,
+    pub w: i32,
+    pub h: i32,
+}
+
+impl Rect {
+    pub fn right(&self) -> i32 {
+        self.x + self.w
+    }
+
+    pub fn bottom(&self) -> i32 {
+        self.y + self.h
+    }
+
+    fn overlaps_x(&self, other: &Rect, slack: i32) -> bool {
+        self.x < other.right() + slack && other.x < self.right() + slack
+    }
+
+    fn overlaps_y(&self, other: &Rect, slack: i32) -> bool {
+        self.y < other.bottom() + slack && other.y < self.bottom() + slack
+    }
+
+    fn overlaps(&self, other: &Rect) -> bool {
+        self.overlaps_x(other, 0) && self.overlaps_y(other, 0)
+    }
+}
+
+// the signals a window exposes to the rest of the desktop
+#[derive(Copy, Clone)]
+pub struct WindowHandle {
+    pub id: StoredValue<String>,
+    pub title: StoredValue<WindowTitle>,
+    pub pos: RwSignal<(i32, i32)>,
+    pub size: RwSignal<(u32, u32)>,
+    pub hidden: RwSignal<bool>,
+    pub expanded: RwSignal<bool>,
+    pub on_top: RwSignal<bool>,
+    pub workspace: RwSignal<usize>,
+    // on a workspace other than the one showing
+    pub away: Memo<bool>,
+    pub titlebar: NodeRef<html::Div>,
+    // the tabs of a tabbed window
+    pub tabs: Option<TabSet>,
+    pub expandable: bool,
+    pub resizable: bool,
+    pub min_size: (u32, u32),
+    pub max_size: Option<(u32, u32)>,
+}
+
+impl WindowHandle {
+    // the window's border box, in page coordinates
+    pub fn outer_rect(&self) -> Rect {
+        let (x, y) = self.pos.get_untracked();
+        let (w, h) = self.size.get_untracked();
+        Rect {
+            x,
+            y,
+            w: w as i32 + 2 * WINDOW_BORDER,
+            h: (h + TITLEBAR_HEIGHT) as i32 + 2 * WINDOW_BORDER,
+        }
+    }
+
+    // the content size that makes the window's border box fill `rect`
+    pub fn size_for(rect: Rect) -> (u32, u32) {
+        (
+            (rect.w - 2 * WINDOW_BORDER).max(0) as u32,
+            (rect.h - 2 * WINDOW_BORDER - TITLEBAR_HEIGHT as i32).max(0) as u32,
+        )
+    }
+
+    pub fn clamp_size(&self, size: (u32, u32)) -> (u32, u32) {
+        clamp_size(size, self.min_size, self.max_size)
+    }
+
+    // moves the window so its border box starts at `rect`, resizing it to fill `rect` if it can
+    fn place(&self, rect: Rect) {
+        self.expanded.set(false);
+        self.pos.set((rect.x, rect.y));
+        if self.resizable {
+            self.size.set(self.clamp_size(Self::size_for(rect)));
+        }
+    }
+
+    // windows are told apart by their position signal
+    fn is(&self, other: &WindowHandle) -> bool {
+        self.pos == other.pos
+    }
+
+    fn is_visible(&self) -> bool {
+        self.is_open_untracked() && !self.expanded.get_untracked()
+    }
+
+    // not closed, and on the workspace that's showing
+    fn is_open(&self) -> bool {
+        !self.hidden.get() && !self.away.get()
+    }
+
+    fn is_open_untracked(&self) -> bool {
+        !self.hidden.get_untracked() && !self.away.get_untracked()
+    }
+
+    // whether it's a window made from tabs that have all gone back to their own windows
+    pub fn is_leftover(&self) -> bool {
+        self.tabs.is_some_and(|t| t.is_leftover())
+    }
+}
+
+// provided around the windows the app owns rather than a page (kept and detached ones). every
+// page starts out on its first workspace, so they're shown on all of them rather than left
+// behind on one
+#[derive(Copy, Clone)]
+pub struct OnEveryWorkspace;
+
+// keeps track of the windows on a page (or inside a meta window) so they can react to each other
+#[derive(Copy, Clone)]
+pub struct Desktop {
+    // how many meta windows deep this desktop is
+    depth: usize,
+    // in the order they were declared
+    windows: RwSignal<Vec<WindowHandle>>,
+    // focus order, bottom to top
+    stack: RwSignal<Vec<WindowHandle>>,
+    // in the order they were closed, most recent last
+    closed: StoredValue<Vec<WindowHandle>>,
+    // the footer's entries, with the `hidden` signal of the window each one opens
+    footer_entries: StoredValue<Vec<(RwSignal<bool>, NodeRef<html::Div>)>>,
+    // the workspace that's showing (windows on the others are out of sight)
+    pub workspace: RwSignal<usize>,
+    pub tile_preview: RwSignal<Option<Rect>>,
+    // the tabbed window a dragged window (or tab) would be merged into if it was let go
+    pub merge_target: RwSignal<Option<WindowHandle>>,
+    // windows picked out with the selection band. they move, minimize and close together
+    selection: RwSignal<Vec<WindowHandle>>,
+    // the band being drawn, in page coordinates
+    pub band: RwSignal<Option<Rect>>,
+    // how far the selection has been dragged (the windows only move once it's let go)
+    pub group_drag: RwSignal<Option<(i32, i32)>>,
+    // on small screens windows are stacked in a single column instead of floating around
+    pub narrow: Signal<bool>,
+}
+
+impl Desktop {
+    pub fn new() -> Self {
+        Self::with_depth(0, use_media_query(NARROW_QUERY))
+    }
+
+    fn with_depth(depth: usize, narrow: Signal<bool>) -> Self {
+        Self {
+            depth,
+            windows: create_rw_signal(Vec::new()),
+            stack: create_rw_signal(Vec::new()),
+            closed: store_value(Vec::new()),
+            footer_entries: store_value(Vec::new()),
+            workspace: create_rw_signal(0),
+            tile_preview: create_rw_signal(None),
+            merge_target: create_rw_signal(None),
+            selection: create_rw_signal(Vec::new()),
+            band: create_rw_signal(None),
+            group_drag: create_rw_signal(None),
+            narrow,
+        }
+    }
+
+    // a fresh desktop for the windows inside a meta window
+    pub fn nested(&self) -> Self {
+        Self::with_depth(self.depth + 1, self.narrow)
+    }
+
+    pub fn is_nested(&self) -> bool {
+        self.depth > 0
+    }
+
+    // the element id for the window with `id`. every meta window shows another copy of
+    // the page, so nested windows get the depth added to keep ids unique in the document
+    pub fn scoped_id(&self, id: &str) -> String {
+        match self.depth {
+            0 => id.to_string(),
+            depth => format!("{id}-d{depth}"),
+        }
+    }
+
+    // adds a window to the desktop until the calling component is cleaned up
+    pub fn register(&self, handle: WindowHandle, start_behind: bool) {
+        let (windows, stack, closed, selection) =
+            (self.windows, self.stack, self.closed, self.selection);
+        windows.update(|w| w.push(handle));
+        stack.update(|s| {
+            if start_behind {
+                s.insert(0, handle)
+            } else {
+                s.push(handle)
+            }
+        });
+        on_cleanup(move || {
+            windows.update(|w| w.retain(|o| !o.is(&handle)));
+            stack.update(|s| s.retain(|o| !o.is(&handle)));
+            closed.update_value(|c| c.retain(|o| !o.is(&handle)));
+            selection.update(|s| s.retain(|o| !o.is(&handle)));
+        });
+    }
+
+    // brings a window to the top of the focus stack
+    pub fn focus(&self, handle: WindowHandle) {
+        if self
+            .stack
+            .with_untracked(|s| s.last().is_some_and(|top| top.is(&handle)))
+        {
+            return;
+        }
+        self.stack.update(|s| {
+            s.retain(|o| !o.is(&handle));
+            s.push(handle);
+        });
+    }
+
+    // the most recently focused window that's still open
+    pub fn is_active(&self, handle: WindowHandle) -> bool {
+        self.stack.with(|s| {
+            s.iter()
+                .rev()
+                .find(|o| o.is_open())
+                .is_some_and(|o| o.is(&handle))
+        })
+    }
+
+    // the active window, if any window is open
+    pub fn focused(&self) -> Option<WindowHandle> {
+        self.stack
+            .with(|s| s.iter().rev().find(|o| o.is_open()).copied())
+    }
+
+    // the window keyboard shortcuts act on
+    fn active(&self) -> Option<WindowHandle> {
+        self.stack
+            .with_untracked(|s| s.iter().rev().find(|o| o.is_open_untracked()).copied())
+    }
+
+    pub fn minimize_active(&self) {
+        if let Some(window) = self.active() {
+            self.close(window);
+        }
+    }
+
+    pub fn show_workspace(&self, workspace: usize) {
+        if self.workspace.get_untracked() != workspace {
+            self.clear_selection();
+            self.workspace.set(workspace);
+        }
+    }
+
+    // sends the focused window to another workspace
+    pub fn move_active_to(&self, workspace: usize) {
+        if let Some(window) = self.active() {
+            window.workspace.set(workspace);
+        }
+    }
+
+    pub fn is_selected(&self, handle: WindowHandle) -> bool {
+        self.selection.with(|s| s.iter().any(|o| o.is(&handle)))
+    }
+
+    // whether the window goes along with other selected windows
+    pub fn in_group(&self, handle: &WindowHandle) -> bool {
+        self.selection
+            .with_untracked(|s| s.len() > 1 && s.iter().any(|o| o.is(handle)))
+    }
+
+    // selects the open windows that touch `rect`
+    pub fn select_in(&self, rect: Rect) {
+        let picked = self.windows.with_untracked(|w| {
+            w.iter()
+                .filter(|o| o.is_visible() && o.outer_rect().overlaps(&rect))
+                .copied()
+                .collect::<Vec<_>>()
+        });
+        self.selection.set(picked);
+    }
+
+    pub fn deselect(&self, handle: WindowHandle) {
+        if self
+            .selection
+            .with_untracked(|s| s.iter().any(|o| o.is(&handle)))
+        {
+            self.selection.update(|s| s.retain(|o| !o.is(&handle)));
+        }
+    }
+
+    pub fn clear_selection(&self) {
+        if self.selection.with_untracked(|s| !s.is_empty()) {
+            self.selection.set(Vec::new());
+        }
+    }
+
+    // moves the rest of `handle`'s selection along by `(dx, dy)`
+    pub fn move_group(&self, handle: &WindowHandle, (dx, dy): (i32, i32)) {
+        for window in self.selection.get_untracked() {
+            if window.is(handle) || !window.is_visible() {
+                continue;
+            }
+            let rect = window.outer_rect();
+            let to = Rect {
+                x: rect.x + dx,
+                y: rect.y + dy,
+                ..rect
+            };
+            window.pos.set(if self.is_nested() {
+                (to.x, to.y)
+            } else {
+                keep_reachable(to)
+            });
+        }
+    }
+
+    // closes (or minimizes, which is the same thing) a window, along with the rest of its selection
+    pub fn close(&self, handle: WindowHandle) {
+        if !self.in_group(&handle) {
+            handle.hidden.set(true);
+            return;
+        }
+        for window in self.selection.get_untracked() {
+            window.hidden.set(true);
+        }
+    }
+
+    pub fn toggle_expand_active(&self) {
+        if let Some(window) = self.active().filter(|w| w.expandable) {
+            window.expanded.update(|e| *e = !*e);
+        }
+    }
+
+    pub fn remember_closed(&self, handle: WindowHandle) {
+        self.closed.update_value(|c| {
+            c.retain(|o| !o.is(&handle));
+            c.push(handle);
+        });
+    }
+
+    // brings back the most recently closed window that's still in the footer
+    pub fn reopen_last(&self) {
+        let last = self
+            .closed
+            .try_update_value(|c| {
+                while let Some(window) = c.pop() {
+                    if window.hidden.get_untracked() && !window.is_leftover() {
+                        return Some(window);
+                    }
+                }
+                None
+            })
+            .flatten();
+        if let Some(window) = last {
+            self.reveal(window.hidden);
+            if let Some(titlebar) = window.titlebar.get_untracked() {
+                let _ = titlebar.focus();
+            }
+        }
+    }
+
+    pub fn z_index(&self, handle: WindowHandle) -> usize {
+        let level = self
+            .stack
+            .with(|s| s.iter().position(|o| o.is(&handle)))
+            .unwrap_or(0);
+        level + 1 + if handle.on_top.get() { ON_TOP_Z } else { 0 }
+    }
+
+    // moves focus to the next (or previous) open window, like alt+tab
+    pub fn cycle_focus(&self, backwards: bool) {
+        let open: Vec<_> = self.stack.with_untracked(|s| {
+            s.iter()
+                .filter(|o| o.is_open_untracked())
+                .copied()
+                .collect()
+        });
+        if open.len() < 2 {
+            return;
+        }
+
+        let next = if backwards {
+            // send the active window to the back
+            let top = open[open.len() - 1];
+            self.stack.update(|s| {
+                s.retain(|o| !o.is(&top));
+                s.insert(0, top);
+            });
+            open[open.len() - 2]
+        } else {
+            open[0]
+        };
+        self.focus(next);
+        if let Some(titlebar) = next.titlebar.get_untracked() {
+            let _ = titlebar.focus();
+        }
+    }
+
+    // opens the window behind a footer entry (bringing it to the front) and, in the single
+    // column layout, scrolls down to it
+    pub fn reveal(&self, hidden: RwSignal<bool>) {
+        hidden.set(false);
+        let Some(window) = self
+            .windows
+            .with_untracked(|w| w.iter().find(|o| o.hidden == hidden).copied())
+        else {
+            return;
+        };
+        // (going over to its workspace if it's on another one)
+        if window.away.get_untracked() {
+            self.show_workspace(window.workspace.get_untracked());
+        }
+        self.focus(window);
+        if let (true, Some(titlebar)) =
+            (self.narrow.get_untracked(), window.titlebar.get_untracked())
+        {
+            titlebar.scroll_into_view();
+            let _ = titlebar.focus();
+        }
+    }
+
+    // whether the window with this `hidden` signal is on the desktop
+    pub fn has(&self, hidden: RwSignal<bool>) -> bool {
+        self.windows.with(|w| w.iter().any(|o| o.hidden == hidden))
+    }
+
+    // lets the window with this `hidden` signal find its footer entry, until the entry's cleaned up
+    pub fn add_footer_entry(&self, hidden: RwSignal<bool>, entry: NodeRef<html::Div>) {
+        let entries = self.footer_entries;
+        entries.update_value(|e| e.push((hidden, entry)));
+        // (a window only has the one entry)
+        on_cleanup(move || entries.update_value(|e| e.retain(|(h, _)| *h != hidden)));
+    }
+
+    pub fn footer_entry(&self, hidden: RwSignal<bool>) -> Option<HtmlElement<html::Div>> {
+        self.footer_entries
+            .with_value(|e| {
+                e.iter()
+                    .rev()
+                    .find(|(h, _)| *h == hidden)
+                    .map(|(_, entry)| *entry)
+            })?
+            .get_untracked()
+    }
+
+    // the page's own windows, in the order they were declared
+    pub fn windows(&self) -> Vec<WindowHandle> {
+        self.windows.get()
+    }
+
+    // the tabs of every tabbed window
+    pub fn tab_sets(&self) -> Vec<TabSet> {
+        self.windows
+            .with_untracked(|w| w.iter().filter_map(|o| o.tabs).collect())
+    }
+
+    // the window whose titlebar is at `(x, y)`, if no other window's in front of it there.
+    // `handle` is looked through, since it's the one being dropped (a caller that isn't
+    // dragging `handle` along has to check the pointer left it first)
+    pub fn titlebar_at(&self, handle: &WindowHandle, (x, y): (i32, i32)) -> Option<WindowHandle> {
+        let top = self.stack.with_untracked(|s| {
+            s.iter()
+                .rev()
+                .filter(|o| !o.is(handle) && o.is_visible())
+                .find(|o| {
+                    let rect = o.outer_rect();
+                    (rect.x..rect.right()).contains(&x) && (rect.y..rect.bottom()).contains(&y)
+                })
+                .copied()
+        })?;
+        let titlebar_bottom = top.outer_rect().y + WINDOW_BORDER + TITLEBAR_HEIGHT as i32;
+        // (windows that can't be merged have no tabs)
+        (top.tabs.is_some() && y < titlebar_bottom).then_some(top)
+    }
+
+    pub fn is_merge_target(&self, handle: WindowHandle) -> bool {
+        self.merge_target.with(|t| t.is_some_and(|t| t.is(&handle)))
+    }
+
+    // pulls windows back to where their titlebars can be grabbed, after the viewport changed
+    // size or saved layouts (from a bigger screen, maybe) were put back
+    pub fn reflow(&self) {
+        if self.is_nested() || self.narrow.get_untracked() {
+            return;
+        }
+        self.windows.with_untracked(|w| {
+            for window in w.iter().filter(|o| !o.hidden.get_untracked()) {
+                let rect = window.outer_rect();
+                let (x, y) = keep_reachable(rect);
+                if (x, y) != (rect.x, rect.y) {
+                    window.pos.set((x, y));
+                }
+            }
+        });
+    }
+
+    fn others(&self, handle: &WindowHandle) -> Vec<WindowHandle> {
+        self.windows.with_untracked(|w| {
+            w.iter()
+                .filter(|o| !o.is(handle) && o.is_visible())
+                .copied()
+                .collect()
+        })
+    }
+
+    // nudges a window being dragged to `rect` onto any nearby screen or window edges
+    pub fn snap(&self, handle: &WindowHandle, rect: Rect) -> (i32, i32) {
+        let area = viewport();
+        let mut x_lines = vec![area.x, area.right()];
+        let mut y_lines = vec![area.y, area.bottom()];
+        if let Some(footer) = footer_rect() {
+            if rect.overlaps_x(&footer, 0) {
+                y_lines.push(footer.y);
+            }
+        }
+        for other in self.others(handle).iter().map(|o| o.outer_rect()) {
+            if rect.overlaps_y(&other, SNAP_DISTANCE) {
+                x_lines.extend([other.x, other.right()]);
+            }
+            if rect.overlaps_x(&other, SNAP_DISTANCE) {
+                y_lines.extend([other.y, other.bottom()]);
+            }
+        }
+
+        (
+            snap_axis(rect.x, rect.w, &x_lines).unwrap_or(rect.x),
+            snap_axis(rect.y, rect.h, &y_lines).unwrap_or(rect.y),
+        )
+    }
+}
+
+#[derive(Copy, Clone, PartialEq)]
+pub enum Arrangement {
+    Cascade,
+    TileHorizontally,
+    TileVertically,
+    FitAll,
+}
+
+impl Arrangement {
+    pub const ALL: [Arrangement; 4] = [
+        Arrangement::Cascade,
+        Arrangement::TileHorizontally,
+        Arrangement::TileVertically,
+        Arrangement::FitAll,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Arrangement::Cascade => "Cascade",
+            Arrangement::TileHorizontally => "Tile Horizontally",
+            Arrangement::TileVertically => "Tile Vertically",
+            Arrangement::FitAll => "Fit All",
+        }
+    }
+}
+
+impl Desktop {
+    // lays out every open window on the page
+    pub fn arrange(&self, arrangement: Arrangement) {
+        let windows: Vec<_> = self.windows.with_untracked(|w| {
+            w.iter()
+                .filter(|o| o.is_open_untracked())
+                .copied()
+                .collect()
+        });
+        if windows.is_empty() {
+            return;
+        }
+        let area = work_area();
+        let inner = Rect {
+            x: area.x + ARRANGE_GAP,
+            y: area.y + ARRANGE_GAP,
+            w: (area.w - 2 * ARRANGE_GAP).max(0),
+            h: (area.h - 2 * ARRANGE_GAP).max(0),
+        };
+        let n = windows.len() as i32;
+
+        match arrangement {
+            Arrangement::Cascade => {
+                let w = inner.w - (n - 1) * CASCADE_STEP;
+                let h = inner.h - (n - 1) * CASCADE_STEP;
+                for (i, window) in windows.iter().enumerate() {
+                    let offset = i as i32 * CASCADE_STEP;
+                    let rect = window.outer_rect();
+                    window.place(Rect {
+                        x: inner.x + offset,
+                        y: inner.y + offset,
+                        w: rect.w.min(w),
+                        h: rect.h.min(h),
+                    });
+                }
+            }
+            Arrangement::TileHorizontally => {
+                let h = (inner.h - (n - 1) * ARRANGE_GAP) / n;
+                for (i, window) in windows.iter().enumerate() {
+                    window.place(Rect {
+                        y: inner.y + i as i32 * (h + ARRANGE_GAP),
+                        h,
+                        ..inner
+                    });
+                }
+            }
+            Arrangement::TileVertically => {
+                let w = (inner.w - (n - 1) * ARRANGE_GAP) / n;
+                for (i, window) in windows.iter().enumerate() {
+                    window.place(Rect {
+                        x: inner.x + i as i32 * (w + ARRANGE_GAP),
+                        w,
+                        ..inner
+                    });
+                }
+            }
+            Arrangement::FitAll => {
+                // shrink (never grow) the current layout so its bounding box fits on screen
+                for window in &windows {
+                    window.expanded.set(false);
+                }
+                let rects: Vec<_> = windows.iter().map(|w| w.outer_rect()).collect();
+                let left = rects.iter().map(|r| r.x).min().unwrap_or(0);
+                let top = rects.iter().map(|r| r.y).min().unwrap_or(0);
+                let right = rects.iter().map(|r| r.right()).max().unwrap_or(0);
+                let bottom = rects.iter().map(|r| r.bottom()).max().unwrap_or(0);
+                let scale = (inner.w as f64 / (right - left).max(1) as f64)
+                    .min(inner.h as f64 / (bottom - top).max(1) as f64)
+                    .min(1.0);
+                let scaled = |v: i32| (v as f64 * scale).round() as i32;
+
+                for (window, rect) in windows.iter().zip(rects) {
+                    window.place(Rect {
+                        x: inner.x + scaled(rect.x - left),
+                        y: inner.y + scaled(rect.y - top),
+                        w: scaled(rect.w),
+                        h: scaled(rect.h),
+                    });
+                }
+            }
+        }
+    }
+}
+
+impl Default for Desktop {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// `(w, h)` kept between a window's min and max sizes (the min wins if they cross)
+fn clamp_size((w, h): (u32, u32), min: (u32, u32), max: Option<(u32, u32)>) -> (u32, u32) {
+    let max = max.unwrap_or((u32::MAX, u32::MAX));
+    (w.min(max.0).max(min.0), h.min(max.1).max(min.1))
+}
+
+// finds the closest position (within SNAP_DISTANCE) that puts either end of a span on one of `lines`
+fn snap_axis(start: i32, len: i32, lines: &[i32]) -> Option<i32> {
+    lines
+        .iter()
+        .flat_map(|&line| [line, line - len])
+        .filter(|&snapped| (snapped - start).abs() <= SNAP_DISTANCE)
+        .min_by_key(|&snapped| (snapped - start).abs())
+}
+
+// where a window at `rect` has to go so its titlebar can still be grabbed
+pub fn keep_reachable(rect: Rect) -> (i32, i32) {
+    keep_within(rect, viewport())
+}
+
+// the same, for the titlebar to stay in `view`
+fn keep_within(rect: Rect, view: Rect) -> (i32, i32) {
+    let visible = MIN_VISIBLE.min(rect.w);
+    (
+        rect.x
+            .min(view.right() - visible)
+            .max(view.x + visible - rect.w),
+        rect.y
+            .min(view.bottom() - TITLEBAR_HEIGHT as i32)
+            .max(view.y),
+    )
+}
+
+// the visible part of the page, in page coordinates
+pub fn viewport() -> Rect {
+    let root = document().document_element();
+    let (scroll_x, scroll_y) = (
+        window().scroll_x().unwrap_or(0.0) as i32,
+        window().scroll_y().unwrap_or(0.0) as i32,
+    );
+    Rect {
+        x: scroll_x,
+        y: scroll_y,
+        w: root.as_ref().map_or(0, |r| r.client_width()),
+        h: root.as_ref().map_or(0, |r| r.client_height()),
+    }
+}
+
+// the footer's border box, in page coordinates
+pub fn footer_rect() -> Option<Rect> {
+    let footer = document().query_selector("footer").ok()??;
+    let r = footer.get_bounding_client_rect();
+    if r.width() == 0.0 {
+        return None;
+    }
+    let view = viewport();
+    Some(Rect {
+        x: r.left() as i32 + view.x,
+        y: r.top() as i32 + view.y,
+        w: r.width() as i32,
+        h: r.height() as i32,
+    })
+}
+
+// the viewport minus the strip the footer sits in
+pub fn work_area() -> Rect {
+    let mut area = viewport();
+    if let Some(footer) = footer_rect() {
+        area.h = (footer.y - area.y).max(0);
+    }
+    area
+}
+
+// which half/quarter of the screen a window dragged to (client) `pointer` should tile to
+pub fn tile_rect(pointer: (i32, i32)) -> Option<Rect> {
+    let view = viewport();
+    let area = work_area();
+    let (left, right) = (pointer.0 <= TILE_EDGE, pointer.0 >= view.w - TILE_EDGE);
+    let (top, bottom) = (pointer.1 <= TILE_EDGE, pointer.1 >= view.h - TILE_EDGE);
+    if !left && !right {
+        return None;
+    }
+
+    let w = area.w / 2;
+    let x = if left { area.x } else { area.x + area.w - w };
+    let (y, h) = match (top, bottom) {
+        (true, _) => (area.y, area.h / 2),
+        (_, true) => (area.y + area.h - area.h / 2, area.h / 2),
+        _ => (area.y, area.h),
+    };
+    Some(Rect { x, y, w, h })
+}
+
+// whether a press is on the desktop itself rather than on a window, an icon, a menu...
+fn on_empty_space(e: &PointerEvent) -> bool {
+    event_target::<web_sys::Element>(e)
+        .closest(".win-outer, footer, .desktop-icon, .menu, #start-menu, #keyboard-help, a, input")
+        .is_ok_and(|o| o.is_none())
+}
+
+// (so the band doesn't select the page's text as well)
+fn set_text_selectable(selectable: bool) {
+    if let Some(body) = document().body() {
+        let style = body.style();
+        let _ = if selectable {
+            style.remove_property("user-select").map(|_| ())
+        } else {
+            style.set_property("user-select", "none")
+        };
+    }
+}
+
+// dragging on empty space draws a band that selects the windows it touches.
+// clicking empty space clears the selection
+#[component]
+pub fn SelectionBand() -> impl IntoView {
+    let desktop = expect_context::<Desktop>();
+    // where the press started, in page coordinates
+    let start = store_value(None::<(i32, i32)>);
+    let page_point = |e: &PointerEvent| {
+        let view = viewport();
+        (e.client_x() + view.x, e.client_y() + view.y)
+    };
+
+    let _ = use_event_listener(use_document(), ev::pointerdown, move |e| {
+        if !e.is_primary() || e.button() != 0 || !on_empty_space(&e) {
+            return;
+        }
+        desktop.clear_selection();
+        if !desktop.narrow.get_untracked() {
+            start.set_value(Some(page_point(&e)));
+        }
+    });
+    let _ = use_event_listener(use_document(), ev::pointermove, move |e| {
+        let Some((x0, y0)) = start.get_value() else {
+            return;
+        };
+        let (x, y) = page_point(&e);
+        let far_enough = (x - x0).abs() > BAND_THRESHOLD || (y - y0).abs() > BAND_THRESHOLD;
+        if desktop.band.get_untracked().is_none() {
+            if !far_enough {
+                return;
+            }
+            set_text_selectable(false);
+        }
+        let band = Rect {
+            x: x0.min(x),
+            y: y0.min(y),
+            w: (x - x0).abs(),
+            h: (y - y0).abs(),
+        };
+        desktop.band.set(Some(band));
+        desktop.select_in(band);
+    });
+    let end = move |_| {
+        start.set_value(None);
+        if desktop.band.get_untracked().is_some() {
+            desktop.band.set(None);
+            set_text_selectable(true);
+        }
+    };
+    let _ = use_event_listener(use_document(), ev::pointerup, end);
+    let _ = use_event_listener(use_document(), ev::pointercancel, end);
+
+    let style = move || {
+        desktop
+            .band
+            .get()
+            .map(|r| {
+                format!(
+                    "left: {}px; top: {}px; width: {}px; height: {}px",
+                    r.x, r.y, r.w, r.h
+                )
+            })
+            .unwrap_or_default()
+    };
+    view! { <div id="selection-band" style=style class:hidden=move || desktop.band.get().is_none()></div> }
+}
+
+#[component]
+pub fn TilePreview() -> impl IntoView {
+    let desktop = expect_context::<Desktop>();
+    let style = move || {
+        desktop
+            .tile_preview
+            .get()
+            .map(|r| {
+                format!(
+                    "left: {}px; top: {}px; width: {}px; height: {}px",
+                    r.x, r.y, r.w, r.h
+                )
+            })
+            .unwrap_or_default()
+    };
+
+    view! { <div id="tile-preview" style=style class:hidden=move || desktop.tile_preview.get().is_none()></div> }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sizes_stay_between_min_and_max() {
+        assert_eq!(clamp_size((50, 500), (150, 70), None), (150, 500));
+        assert_eq!(
+            clamp_size((900, 20), (150, 70), Some((800, 600))),
+            (800, 70)
+        );
+        assert_eq!(
+            clamp_size((300, 300), (150, 70), Some((800, 600))),
+            (300, 300)
+        );
+        assert_eq!(
+            clamp_size((300, 300), (400, 70), Some((200, 600))),
+            (400, 300)
+        );
+    }
+
+    #[test]
+    fn snaps_either_end_to_the_closest_line() {
+        assert_eq!(snap_axis(95, 200, &[100]), Some(100));
+        assert_eq!(snap_axis(-95, 200, &[100, 500]), Some(-100));
+        assert_eq!(snap_axis(290, 200, &[100, 500]), Some(300));
+        assert_eq!(snap_axis(102, 200, &[100, 105]), Some(100));
+        assert_eq!(snap_axis(150, 200, &[100, 500]), None);
+        assert_eq!(snap_axis(150, 200, &[]), None);
+    }
+
+    #[test]
+    fn titlebars_stay_in_view() {
+        let view = Rect {
+            x: 0,
+            y: 300,
+            w: 1000,
+            h: 800,
+        };
+        let at = |x, y| Rect {
+            x,
+            y,
+            w: 400,
+            h: 300,
+        };
+        assert_eq!(keep_within(at(20, 400), view), (20, 400));
+        assert_eq!(keep_within(at(-350, 400), view), (-300, 400));
+        assert_eq!(keep_within(at(950, 400), view), (900, 400));
+        assert_eq!(keep_within(at(20, 100), view), (20, 300));
+        assert_eq!(
+            keep_within(at(20, 1200), view),
+            (20, 1100 - TITLEBAR_HEIGHT as i32)
+        );
+        // (narrow windows only have to keep what they've got on screen)
+        let narrow = Rect {
+            w: 60,
+            ..at(-100, 400)
+        };
+        assert_eq!(keep_within(narrow, view), (0, 400));
+    }
+}
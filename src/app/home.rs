@@ -1,145 +1,205 @@
+use crate::app::desktop::Desktop;
 use crate::app::music::MusicLinkWindow;
 use crate::app::{
-    AdWindow, Footer, GoatCounter, JohnWindow, LinkWindow, LoadingWindow, LoadingWindowVariant,
-    Webring, WebringWindow, Window, WindowContent, WindowPos,
+    page::{footer_items, FrameExtras, Label, WindowFlags, WindowSlot, WindowSpec},
+    title::TitlePart,
+    AdWindow, FileViewers, Footer, GoatCounter, JohnWindow, LinkWindow, LoadingWindow,
+    LoadingWindowVariant, Webring, WebringWindow, WindowContent, WindowPos,
 };
 // use leptos::logging::log;
 use leptos::*;
-use web_sys::MouseEvent;
 
-#[component]
-fn HomePage(
-    recursions: usize,
-    mut sigs: Vec<(WindowPos, RwSignal<bool>)>,
-    z_idx: RwSignal<usize>,
-) -> impl IntoView {
-    let (mut loading_pos, loading_hidden) = sigs[0];
-    let (mut portfolio_pos, portfolio_hidden) = sigs[1];
-    let (mut music_pos, music_hidden) = sigs[2];
-    let (mut tp_pos, tp_hidden) = sigs[3];
-    let (mut webring_pos, webring_hidden) = sigs[4];
-    let (mut meta_pos, meta_hidden) = sigs[5];
-    let (mut ad_pos, ad_hidden) = sigs[6];
-    let (mut john_pos, john_hidden) = sigs[7];
+pub const WINDOWS: &[WindowSpec] = &[
+    WindowSpec {
+        id: "loading-win",
+        title: &[TitlePart::Text("\"Inspiration\"")],
+        label: Label::Title,
+        pos: (20, 20),
+        size: (225, 170),
+        flags: WindowFlags {
+            expandable: false,
+            rainbow: true,
+            ..WindowFlags::DEFAULT
+        },
+        view: |win| {
+            view! { <LoadingWindow win=win variant=LoadingWindowVariant::Default/> }.into_view()
+        },
+    },
+    WindowSpec {
+        id: "portfolio-link-win",
+        title: &[TitlePart::Text("Portfolio")],
+        label: Label::Title,
+        pos: (280, 20),
+        size: (170, 220),
+        flags: WindowFlags {
+            expandable: false,
+            rainbow: true,
+            ..WindowFlags::DEFAULT
+        },
+        view: |win| {
+            view! { <LinkWindow win=win bg_img="/assets/file-icon.svg" src="/portfolio"/> }
+                .into_view()
+        },
+    },
+    WindowSpec {
+        id: "music-link-win",
+        title: &[TitlePart::Text("Music")],
+        label: Label::Title,
+        pos: (20, 262),
+        size: (225, 225),
+        flags: WindowFlags {
+            expandable: false,
+            rainbow: true,
+            ..WindowFlags::DEFAULT
+        },
+        view: |win| view! { <MusicLinkWindow win=win/> }.into_view(),
+    },
+    WindowSpec {
+        id: "tp-link-win",
+        title: &[TitlePart::Text("toki pona")],
+        label: Label::Title,
+        pos: (280, 309),
+        size: (170, 178),
+        flags: WindowFlags {
+            expandable: false,
+            diag_tp: true,
+            ..WindowFlags::DEFAULT
+        },
+        view: |win| view! { <LinkWindow win=win bg_img="/assets/itan.svg" src="/tp"/> }.into_view(),
+    },
+    WindowSpec {
+        id: "bucket-webring-win",
+        title: &[TitlePart::Text("Bucket Webring")],
+        label: Label::Short("Webring"),
+        pos: (20, 559),
+        size: (430, 70),
+        flags: WindowFlags {
+            expandable: false,
+            resizable: false,
+            ..WindowFlags::DEFAULT
+        },
+        view: |win| view! { <WebringWindow win=win webring=Webring::Bucket/> }.into_view(),
+    },
+    WindowSpec {
+        id: "ad-win",
+        title: &[TitlePart::Text("Advertisement")],
+        label: Label::Unlisted,
+        pos: (485, 20),
+        size: (200, 100),
+        flags: WindowFlags {
+            expandable: false,
+            resizable: false,
+            start_behind: true,
+            always_on_top: true,
+            ..WindowFlags::DEFAULT
+        },
+        view: |win| view! { <AdWindow win=win/> }.into_view(),
+    },
+    WindowSpec {
+        id: "meta-win",
+        title: &[TitlePart::Text("Meta...")],
+        label: Label::Short("Meta"),
+        pos: (485, 192),
+        size: (200, 437),
+        flags: WindowFlags {
+            rainbow: true,
+            ..WindowFlags::DEFAULT
+        },
+        view: |win| view! { <MetaWindow win=win/> }.into_view(),
+    },
+    WindowSpec {
+        id: "john-win",
+        title: &[TitlePart::Text("Johnvertisement")],
+        label: Label::Title,
+        pos: (20, 701),
+        size: (665, 82),
+        flags: WindowFlags {
+            expandable: false,
+            start_behind: true,
+            always_on_top: true,
+            ..WindowFlags::DEFAULT
+        },
+        view: |win| view! { <JohnWindow win=win/> }.into_view(),
+    },
+];
 
-    let footer_items = vec![
-        ("\"Inspiration\"", loading_hidden),
-        ("Portfolio", portfolio_hidden),
-        ("Music", music_hidden),
-        ("toki pona", tp_hidden),
-        ("Webring", webring_hidden),
-        ("Meta", meta_hidden),
-        ("Johnvertisement", john_hidden),
-    ];
+// the home page's windows, for the meta window to show again one level down
+#[derive(Clone)]
+struct HomeLevel {
+    recursions: usize,
+    slots: Vec<WindowSlot>,
+}
 
-    if recursions == 1 {
-        if let WindowPos::Sig(p) = loading_pos {
-            loading_pos = WindowPos::OffsetSignal(p);
-        };
-        if let WindowPos::Sig(p) = portfolio_pos {
-            portfolio_pos = WindowPos::OffsetSignal(p);
-        };
-        if let WindowPos::Sig(p) = music_pos {
-            music_pos = WindowPos::OffsetSignal(p);
-        };
-        if let WindowPos::Sig(p) = tp_pos {
-            tp_pos = WindowPos::OffsetSignal(p);
-        };
-        if let WindowPos::Sig(p) = webring_pos {
-            webring_pos = WindowPos::OffsetSignal(p);
-        };
-        if let WindowPos::Sig(p) = meta_pos {
-            meta_pos = WindowPos::OffsetSignal(p);
-        };
-        if let WindowPos::Sig(p) = ad_pos {
-            ad_pos = WindowPos::OffsetSignal(p);
-        };
-        if let WindowPos::Sig(p) = john_pos {
-            john_pos = WindowPos::OffsetSignal(p);
-        };
+#[component]
+fn HomePage(recursions: usize, slots: Vec<WindowSlot>) -> impl IntoView {
+    let footer_items = footer_items(&slots);
 
-        sigs = vec![
-            (loading_pos, loading_hidden),
-            (portfolio_pos, portfolio_hidden),
-            (music_pos, music_hidden),
-            (tp_pos, tp_hidden),
-            (webring_pos, webring_hidden),
-            (meta_pos, meta_hidden),
-            (ad_pos, ad_hidden),
-            (john_pos, john_hidden),
-        ];
-    }
+    // the copies inside meta windows are moved down, out of the way of its titlebar
+    let slots = if recursions == 1 {
+        slots
+            .into_iter()
+            .map(|win| match win.pos {
+                WindowPos::Sig(p) => WindowSlot {
+                    pos: WindowPos::OffsetSignal(p),
+                    ..win
+                },
+                _ => win,
+            })
+            .collect()
+    } else {
+        slots
+    };
+    provide_context(HomeLevel {
+        recursions,
+        slots: slots.clone(),
+    });
 
-    let z_idx = Some(z_idx);
+    // (the johnvertisement is only on the real page)
+    let windows = slots
+        .into_iter()
+        .filter(|win| recursions == 0 || win.spec.id != "john-win")
+        .map(WindowSlot::view_on_page)
+        .collect::<Vec<_>>();
 
     view! {
-        <LoadingWindow   pos=loading_pos   size=(225, 170) hidden=loading_hidden   z_idx=z_idx variant=LoadingWindowVariant::Default/>
-        <LinkWindow      pos=portfolio_pos size=(170, 220) hidden=portfolio_hidden z_idx=z_idx id="portfolio-link-win" title="Portfolio".to_string() bg_img="/assets/file-icon.svg" src="/portfolio"/>
-        <MusicLinkWindow pos=music_pos     size=(225, 225) hidden=music_hidden     z_idx=z_idx/> // music link window
-        <LinkWindow      pos=tp_pos        size=(170, 178) hidden=tp_hidden        z_idx=z_idx id="tp-link-win"        title="toki pona".to_string() bg_img="/assets/itan.svg" src="/tp" diag_tp=true/>
-        <WebringWindow   pos=webring_pos   size=(430, 70)  hidden=webring_hidden   z_idx=z_idx webring=Webring::Bucket/>
-        <AdWindow        pos=ad_pos        size=(200, 100) hidden=ad_hidden        z_idx=z_idx/>
-        <MetaWindow      pos=meta_pos      size=(200, 437) hidden=meta_hidden      z_idx=z_idx.unwrap() recursions={recursions + 1} sigs=sigs/>
-        <div class:hidden=move || {recursions > 0}>
+        { windows }
+        // (only the real page has a footer, its menus would be duplicated otherwise)
+        { (recursions == 0).then(|| view! {
             <div style="height: 65px"></div> // large spacer
             <Footer items=footer_items/>     // footer
-        </div>
-        <div class:hidden=move || {recursions > 0} style="height: 20px"></div> // small spacer
+            <div style="height: 20px"></div> // small spacer
+        }) }
     }
 }
 
 #[component]
 pub fn HomePageWrap() -> impl IntoView {
-    let loading_hidden = create_rw_signal(false);
-    let portfolio_hidden = create_rw_signal(false);
-    let music_hidden = create_rw_signal(false);
-    let tp_hidden = create_rw_signal(false);
-    let webring_hidden = create_rw_signal(false);
-    let meta_hidden = create_rw_signal(false);
-    let ad_hidden = create_rw_signal(false);
-    let john_hidden = create_rw_signal(false);
+    // every level of meta windows moves and closes the same windows
+    let files = FileViewers::new();
+    let slots = WINDOWS
+        .iter()
+        .map(|spec| WindowSlot {
+            pos: WindowPos::Sig(create_rw_signal(spec.pos)),
+            ..WindowSlot::new(spec, files)
+        })
+        .collect::<Vec<_>>();
 
-    let loading_pos = WindowPos::Sig(create_rw_signal((20, 20)));
-    let portfolio_pos = WindowPos::Sig(create_rw_signal((280, 20)));
-    let music_pos = WindowPos::Sig(create_rw_signal((20, 262)));
-    let tp_pos = WindowPos::Sig(create_rw_signal((280, 309)));
-    let webring_pos = WindowPos::Sig(create_rw_signal((20, 559)));
-    let meta_pos = WindowPos::Sig(create_rw_signal((485, 192)));
-    let ad_pos = WindowPos::Sig(create_rw_signal((485, 20)));
-    let john_pos = WindowPos::Sig(create_rw_signal((20, 701)));
-
-    let sigs = vec![
-        (loading_pos, loading_hidden),
-        (portfolio_pos, portfolio_hidden),
-        (music_pos, music_hidden),
-        (tp_pos, tp_hidden),
-        (webring_pos, webring_hidden),
-        (meta_pos, meta_hidden),
-        (ad_pos, ad_hidden),
-        (john_pos, john_hidden),
-    ];
-
-    let z_idx = create_rw_signal(1);
     view! {
-        <HomePage recursions=0 z_idx=z_idx sigs=sigs/>
-        <JohnWindow      pos=john_pos      size=(665, 82)  hidden=john_hidden      z_idx=Some(z_idx)/>
+        <HomePage recursions=0 slots=slots/>
         <GoatCounter path="/"/>
     }
 }
 
 const STACK_OVERFLOW_LIMIT: usize = 8;
 #[component]
-fn MetaWindow(
-    pos: WindowPos,
-    size: (u32, u32),
-    hidden: RwSignal<bool>,
-    z_idx: RwSignal<usize>,
-    recursions: usize,
-    sigs: Vec<(WindowPos, RwSignal<bool>)>,
-) -> impl IntoView {
-    let size = create_rw_signal(size);
+fn MetaWindow(win: WindowSlot) -> impl IntoView {
+    let size = create_rw_signal(win.spec.size);
+    let desktop = expect_context::<Desktop>();
+    let HomeLevel { recursions, slots } = expect_context::<HomeLevel>();
+    let recursions = recursions + 1;
+    let slots = store_value(slots);
     let deeper = create_rw_signal(false);
-    let go_deeper = move |_| {
+    let go_deeper = move || {
         deeper.set(true);
         size.set((720, 696));
     };
@@ -147,10 +207,8 @@ fn MetaWindow(
         <div
             class="meta-preview"
             class:hidden=move || deeper()
-            on:mousedown=go_deeper
-            on:keydown=move |k| if k.key() == "Enter" {
-                go_deeper(MouseEvent::new("").unwrap())
-            }
+            on:pointerup=move |_| go_deeper()
+            on:keydown=move |k| if k.key() == "Enter" { go_deeper() }
             tabindex=0
         >
             <video
@@ -164,17 +222,40 @@ fn MetaWindow(
             </video>
         </div>
         <div class="meta-meta scroll" style="height: 844px" class:hidden=move || !deeper()>
-            {
+            // the next level down only exists while it's being looked at (going back up tears it down)
+            { move || deeper().then(|| {
                 if recursions <= STACK_OVERFLOW_LIMIT {
-                    view! { <div><HomePage recursions=recursions z_idx=z_idx sigs=sigs/></div> }
+                    view! { <div><Provider value=desktop.nested()><HomePage recursions=recursions slots=slots.get_value()/></Provider></div> }
                 } else {
-                    view! { <div><LoadingWindow pos=WindowPos::Val((20, 55)) size=(300, 100) hidden=hidden variant=LoadingWindowVariant::StackOverflow/></div> }
+                    view! { <div><Provider value=desktop.nested()>{ WindowSlot { hidden: win.hidden, ..WindowSlot::new(&STACK_OVERFLOW_WINDOW, win.files) }.view() }</Provider></div> }
                 }
-            }
+            }) }
         </div>
     </div> });
 
-    view! {
-        <Window id="meta-win" title="Meta...".to_string() content=content pos=pos size=size hidden=hidden z_idx=Some(z_idx) min_button=Some((deeper, size)) rainbow=true/>
-    }
+    win.frame_with(
+        content,
+        FrameExtras {
+            size: Some(size),
+            min_button: Some((deeper, size)),
+            ..Default::default()
+        },
+    )
 }
+
+const STACK_OVERFLOW_WINDOW: WindowSpec = WindowSpec {
+    id: "loading-win",
+    title: &[TitlePart::Text("Uh-oh! The stack overflowed")],
+    label: Label::Unlisted,
+    pos: (20, 55),
+    size: (300, 100),
+    flags: WindowFlags {
+        expandable: false,
+        rainbow: true,
+        persist: false,
+        ..WindowFlags::DEFAULT
+    },
+    view: |win| {
+        view! { <LoadingWindow win=win variant=LoadingWindowVariant::StackOverflow/> }.into_view()
+    },
+};
@@ -0,0 +1,214 @@
+use crate::app::{
+    desktop::{Desktop, WindowHandle},
+    persist, ROUTES,
+};
+use leptos::{ev::PointerEvent, *};
+use leptos_router::{use_location, use_navigate};
+use web_sys::wasm_bindgen::JsCast;
+
+// the grid icons are laid out on until they're moved (to the right of the page's windows)
+const ICON_WIDTH: i32 = 100;
+const ICON_HEIGHT: i32 = 90;
+const ICON_MARGIN: i32 = 10;
+const ICONS_PER_COLUMN: usize = 7;
+// how far (in px) the pointer has to move before a press becomes a drag
+const DRAG_THRESHOLD: i32 = 4;
+
+#[derive(Copy, Clone)]
+enum IconTarget {
+    Window(WindowHandle),
+    Route(&'static str),
+}
+
+#[derive(Clone)]
+struct Icon {
+    key: String,
+    label: String,
+    target: IconTarget,
+}
+
+// where the icons' grid starts on the page at `path`: past the right edge of its windows as
+// they're first laid out, so the icons aren't hidden under them
+fn grid_left(path: &str) -> i32 {
+    let windows_right = ROUTES
+        .iter()
+        .find(|page| page.path == path)
+        .and_then(|page| {
+            page.windows
+                .iter()
+                .map(|spec| spec.pos.0 + spec.size.0 as i32)
+                .max()
+        })
+        .unwrap_or(0);
+    windows_right + 2 * ICON_MARGIN
+}
+
+#[derive(Copy, Clone)]
+struct DragStart {
+    pointer: (i32, i32),
+    pos: (i32, i32),
+    moved: bool,
+}
+
+// a layer behind the windows with an icon for each of the page's windows and each of the other pages
+#[component]
+pub fn DesktopIcons() -> impl IntoView {
+    let desktop = expect_context::<Desktop>();
+    let pathname = use_location().pathname;
+    let selected = create_rw_signal(None::<String>);
+    create_effect(move |_| {
+        pathname.track();
+        selected.set(None);
+    });
+
+    let icons = move || {
+        let path = pathname();
+        // the other pages come first, so they keep their spots as file viewers come and go
+        ROUTES
+            .iter()
+            .filter(|page| page.path != path)
+            .filter_map(|page| {
+                page.label.map(|label| Icon {
+                    key: format!("route{}", page.path.replace('/', "-")),
+                    label: label.to_string(),
+                    target: IconTarget::Route(page.path),
+                })
+            })
+            .chain(
+                desktop
+                    .windows()
+                    .into_iter()
+                    .filter(|w| !w.is_leftover())
+                    .map(|w| Icon {
+                        key: w.id.get_value(),
+                        label: w.title.with_value(|t| t.plain()),
+                        target: IconTarget::Window(w),
+                    }),
+            )
+            .enumerate()
+            // icons start over on every page
+            .map(|(i, icon)| (path.clone(), i, icon))
+            .collect::<Vec<_>>()
+    };
+
+    view! {
+        <div id="desktop-icons" role="listbox" aria-label="desktop">
+            <For
+                each=icons
+                key=|(path, _, icon)| (path.clone(), icon.key.clone())
+                children=move |(path, i, icon)| view! { <DesktopIcon icon=icon index=i left=grid_left(&path) selected=selected/> }
+            />
+        </div>
+    }
+}
+
+#[component]
+fn DesktopIcon(
+    icon: Icon,
+    index: usize,
+    left: i32,
+    selected: RwSignal<Option<String>>,
+) -> impl IntoView {
+    let desktop = expect_context::<Desktop>();
+    let Icon { key, label, target } = icon;
+    let key = store_value(key);
+
+    let col = (index / ICONS_PER_COLUMN) as i32;
+    let row = (index % ICONS_PER_COLUMN) as i32;
+    let pos = create_rw_signal((left + col * ICON_WIDTH, ICON_MARGIN + row * ICON_HEIGHT));
+    key.with_value(|key| persist::persist_icon(key, pos));
+
+    let is_selected = move || key.with_value(|key| selected.with(|s| s.as_ref() == Some(key)));
+    let select = move || selected.set(Some(key.get_value()));
+    let open = move || match target {
+        IconTarget::Window(window) => desktop.reveal(window.hidden),
+        IconTarget::Route(route) => use_navigate()(route, Default::default()),
+    };
+
+    let drag = create_rw_signal(None::<DragStart>);
+    let drag_start = move |e: PointerEvent| {
+        select();
+        if !e.is_primary() || e.button() != 0 {
+            return;
+        }
+        let _ = event_target::<web_sys::Element>(&e).set_pointer_capture(e.pointer_id());
+        drag.set(Some(DragStart {
+            pointer: (e.client_x(), e.client_y()),
+            pos: pos.get_untracked(),
+            moved: false,
+        }));
+    };
+    let drag_move = move |e: PointerEvent| {
+        if let Some(start) = drag.get_untracked() {
+            let (dx, dy) = (
+                e.client_x() - start.pointer.0,
+                e.client_y() - start.pointer.1,
+            );
+            if start.moved || dx.abs() > DRAG_THRESHOLD || dy.abs() > DRAG_THRESHOLD {
+                drag.set(Some(DragStart {
+                    moved: true,
+                    ..start
+                }));
+                pos.set(((start.pos.0 + dx).max(0), (start.pos.1 + dy).max(0)));
+            }
+        }
+    };
+    let drag_end = move |_: PointerEvent| drag.set(None);
+
+    // arrow keys walk through the icons in order
+    let focus_icon = move |icon: Option<web_sys::Element>| {
+        if let Some(icon) = icon.and_then(|i| i.dyn_into::<web_sys::HtmlElement>().ok()) {
+            let _ = icon.focus();
+        }
+    };
+    let on_keydown = move |k: ev::KeyboardEvent| {
+        let this = event_target::<web_sys::Element>(&k);
+        let parent = this.parent_element();
+        match k.key().as_str() {
+            "Enter" => open(),
+            "ArrowDown" | "ArrowRight" => focus_icon(this.next_element_sibling()),
+            "ArrowUp" | "ArrowLeft" => focus_icon(this.previous_element_sibling()),
+            "Home" => focus_icon(parent.and_then(|p| p.first_element_child())),
+            "End" => focus_icon(parent.and_then(|p| p.last_element_child())),
+            _ => return,
+        }
+        k.prevent_default();
+    };
+
+    // only the selected icon (or the first one) is in the tab order
+    let tabindex = move || {
+        let first = index == 0 && selected.with(|s| s.is_none());
+        if is_selected() || first {
+            0
+        } else {
+            -1
+        }
+    };
+    let closed = move || match target {
+        IconTarget::Window(window) => window.hidden.get(),
+        IconTarget::Route(_) => false,
+    };
+
+    view! {
+        <div
+            class="desktop-icon"
+            class:desktop-icon-route=matches!(target, IconTarget::Route(_))
+            class:desktop-icon-closed=closed
+            class:selected=is_selected
+            role="option"
+            aria-selected=move || is_selected().to_string()
+            style=move || format!("left: {}px; top: {}px", pos().0, pos().1)
+            tabindex=tabindex
+            on:focus=move |_| select()
+            on:pointerdown=drag_start
+            on:pointermove=drag_move
+            on:pointerup=drag_end
+            on:pointercancel=drag_end
+            on:dblclick=move |_| open()
+            on:keydown=on_keydown
+        >
+            <div class="desktop-icon-img"></div>
+            <p class="title">{label}</p>
+        </div>
+    }
+}
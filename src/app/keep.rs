@@ -0,0 +1,150 @@
+use crate::app::{
+    desktop::OnEveryWorkspace,
+    menu::MenuItem,
+    page::{WindowSlot, WindowSpec},
+    FileDoc, FileViewers, FileWindows,
+};
+use leptos::*;
+use leptos_router::use_location;
+
+// where documents the app keeps open are shown
+const KEPT_FILES_POS: (i32, i32) = (60, 60);
+const KEPT_FILES_SIZE: (u32, u32) = (500, 700);
+
+// windows (and documents) kept open on every page. the app owns them rather than
+// a page, so they (and whatever's playing in them) survive changing routes. a
+// page leaves out its own copy of a window while the app keeps it
+#[derive(Copy, Clone)]
+pub struct KeptWindows {
+    owner: Owner,
+    windows: RwSignal<Vec<KeptWindow>>,
+    files: FileViewers,
+    // the file viewers of the page that's showing, for documents that stop being kept
+    page_files: StoredValue<Option<FileViewers>>,
+}
+
+// a kept window, and the page it was kept from. pages reuse window ids (every page has
+// its own "loading-win"), so a window is only the same one on the same page
+#[derive(Clone)]
+pub struct KeptWindow {
+    route: String,
+    pub win: WindowSlot,
+}
+
+// provided around a kept window, with the route it was kept from
+#[derive(Clone)]
+pub struct KeptFrom(pub String);
+
+impl KeptWindow {
+    pub fn key(&self) -> (String, &'static str) {
+        (self.route.clone(), self.win.spec.id)
+    }
+
+    fn is(&self, route: &str, id: &str) -> bool {
+        self.route == route && self.win.spec.id == id
+    }
+}
+
+impl KeptWindows {
+    pub fn new() -> Self {
+        Self {
+            owner: Owner::current().expect("kept windows belong to the app"),
+            windows: create_rw_signal(Vec::new()),
+            files: FileViewers::with_prefix("file-win-kept-"),
+            page_files: store_value(None),
+        }
+    }
+
+    pub fn windows(&self) -> RwSignal<Vec<KeptWindow>> {
+        self.windows
+    }
+
+    pub fn files(&self) -> FileViewers {
+        self.files
+    }
+
+    // whether the window `id` of the page at `route` is kept
+    pub fn is_kept(&self, route: &str, id: &str) -> bool {
+        self.windows
+            .with(|w| w.iter().any(|kept| kept.is(route, id)))
+    }
+
+    fn toggle(&self, route: &str, spec: &'static WindowSpec) {
+        if self
+            .windows
+            .with_untracked(|w| w.iter().any(|kept| kept.is(route, spec.id)))
+        {
+            self.windows
+                .update(|w| w.retain(|kept| !kept.is(route, spec.id)));
+        } else {
+            // (the window has to outlive the page it was kept from)
+            let win = with_owner(self.owner, || WindowSlot::new(spec, self.files));
+            let route = route.to_string();
+            self.windows.update(|w| w.push(KeptWindow { route, win }));
+        }
+    }
+
+    // the window menu's entry for keeping a page's window open
+    pub fn menu_item(&self, spec: &'static WindowSpec) -> MenuItem {
+        let kept = *self;
+        let route = store_value(use_location().pathname.get_untracked());
+        MenuItem::new("Keep on every page", move || {
+            route.with_value(|route| kept.toggle(route, spec))
+        })
+        .checked(Signal::derive(move || {
+            route.with_value(|route| kept.is_kept(route, spec.id))
+        }))
+    }
+
+    // the same for a document in one of the viewers of `files`. a document stops being
+    // kept by going back to the page's viewers (or closing, if the page doesn't have any)
+    pub(super) fn file_menu_item(&self, files: FileViewers, doc: FileDoc) -> MenuItem {
+        let kept = *self;
+        let is_kept = files.is(&kept.files);
+        MenuItem::new("Keep on every page", move || {
+            files.close(doc.key);
+            if !is_kept {
+                kept.files.open(doc.src, doc.title, true);
+            } else if let Some(page_files) = kept.page_files.get_value() {
+                page_files.open(doc.src, doc.title, true);
+            }
+        })
+        .checked(Signal::derive(move || is_kept))
+    }
+
+    pub fn reveal_file(&self, src: &'static str, title: &'static str) -> bool {
+        self.files.reveal(src, title)
+    }
+
+    // lets documents that stop being kept go back to the page showing `files`
+    pub fn set_page_files(&self, files: FileViewers) {
+        let page_files = self.page_files;
+        page_files.set_value(Some(files));
+        // (the next page may have already put its own in)
+        on_cleanup(move || {
+            if page_files.with_value(|p| p.is_some_and(|p| p.is(&files))) {
+                page_files.set_value(None);
+            }
+        });
+    }
+}
+
+impl Default for KeptWindows {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// the windows the app keeps open, outside the routes so they stay put when the page changes
+#[component]
+pub fn KeptWindowLayer() -> impl IntoView {
+    let kept = expect_context::<KeptWindows>();
+    provide_context(OnEveryWorkspace);
+    view! {
+        <For each=kept.windows key=|kept| kept.key() children=|kept| {
+            provide_context(KeptFrom(kept.route));
+            kept.win.view()
+        }/>
+        <FileWindows pos=KEPT_FILES_POS size=KEPT_FILES_SIZE files=kept.files/>
+    }
+}
@@ -0,0 +1,132 @@
+use leptos::*;
+use web_sys::wasm_bindgen::JsCast;
+
+// one entry of a `Menu`
+#[derive(Copy, Clone)]
+pub struct MenuItem {
+    label: MaybeSignal<&'static str>,
+    action: Callback<()>,
+    // makes the entry a checkbox
+    checked: Option<Signal<bool>>,
+}
+
+impl MenuItem {
+    pub fn new(label: impl Into<MaybeSignal<&'static str>>, action: impl Fn() + 'static) -> Self {
+        Self {
+            label: label.into(),
+            action: Callback::new(move |_| action()),
+            checked: None,
+        }
+    }
+
+    pub fn checked(self, checked: impl Into<Signal<bool>>) -> Self {
+        Self {
+            checked: Some(checked.into()),
+            ..self
+        }
+    }
+}
+
+// a retro popup menu: arrows move between entries, enter picks one, escape (or
+// clicking anywhere else) closes it. it's placed by its id/class in css, unless
+// it's given a point to open at (like a context menu)
+#[component]
+pub fn Menu(
+    open: RwSignal<bool>,
+    items: Vec<MenuItem>,
+    #[prop(optional)] id: Option<&'static str>,
+    #[prop(optional)] at: Option<RwSignal<(i32, i32)>>,
+) -> impl IntoView {
+    let entries = store_value(
+        items
+            .iter()
+            .map(|_| create_node_ref::<html::Div>())
+            .collect::<Vec<_>>(),
+    );
+    let count = items.len();
+    let focus_entry = move |index: usize| {
+        if let Some(entry) = entries.with_value(|e| e.get(index).and_then(|e| e.get_untracked())) {
+            let _ = entry.focus();
+        }
+    };
+
+    // take focus when opened, so the keyboard works right away
+    create_effect(move |_| {
+        if open() {
+            focus_entry(0);
+        }
+    });
+
+    let menu = create_node_ref::<html::Div>();
+    // focus moving somewhere outside the menu (a click elsewhere, tab) closes it
+    let focus_out = move |e: ev::FocusEvent| {
+        let inside = match (menu.get_untracked(), e.related_target()) {
+            (Some(menu), Some(to)) => menu.contains(Some(to.unchecked_ref())),
+            _ => false,
+        };
+        if !inside {
+            open.set(false);
+        }
+    };
+
+    let style = move || {
+        at.map(|at| {
+            let (x, y) = at();
+            format!("left: {x}px; top: {y}px")
+        })
+    };
+
+    view! {
+        <div
+            id=id
+            class="menu"
+            class:menu-at=at.is_some()
+            role="menu"
+            style=style
+            class:hidden=move || !open()
+            node_ref=menu
+            on:focusout=focus_out
+        >{
+            items
+                .into_iter()
+                .enumerate()
+                .map(|(i, item)| {
+                    let pick = move || {
+                        open.set(false);
+                        (item.action)(());
+                    };
+                    // node refs only exist in the browser
+                    #[cfg_attr(feature = "ssr", allow(unused_variables))]
+                    let entry = entries.with_value(|e| e[i]);
+                    view! {
+                        <div
+                            class="title menu-item"
+                            role=if item.checked.is_some() { "menuitemcheckbox" } else { "menuitem" }
+                            aria-checked=move || item.checked.map(|c| c().to_string())
+                            class:menu-checked=move || item.checked.is_some_and(|c| c())
+                            tabindex=-1
+                            node_ref=entry
+                            on:pointerdown=move |e| {
+                                e.prevent_default();
+                                pick();
+                            }
+                            on:keydown=move |k| {
+                                match k.key().as_str() {
+                                    "ArrowDown" => focus_entry((i + 1) % count),
+                                    "ArrowUp" => focus_entry((i + count - 1) % count),
+                                    "Home" => focus_entry(0),
+                                    "End" => focus_entry(count - 1),
+                                    "Enter" | " " => pick(),
+                                    "Escape" => open.set(false),
+                                    _ => return,
+                                }
+                                k.prevent_default();
+                                k.stop_propagation();
+                            }
+                        >{item.label}</div>
+                    }
+                })
+                .collect::<Vec<_>>()
+        }</div>
+    }
+}
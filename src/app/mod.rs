@@ -1,23 +1,84 @@
-use leptos::{ev::MouseEvent, *};
+use leptos::{
+    ev::PointerEvent,
+    leptos_dom::helpers::{
+        request_animation_frame_with_handle, set_timeout_with_handle, AnimationFrameRequestHandle,
+        TimeoutHandle,
+    },
+    *,
+};
 use leptos_meta::*;
 use leptos_router::*;
-use leptos_use::{use_event_listener, use_event_listener_with_options, UseEventListenerOptions};
+use leptos_use::{use_document, use_event_listener, use_window};
 use rand::seq::SliceRandom;
+use web_sys::wasm_bindgen::JsCast;
 
+pub mod deep_link;
+pub mod desktop;
 pub mod home;
+pub mod icons;
 pub mod kalama_sin;
+pub mod keep;
+pub mod menu;
 pub mod music;
 pub mod nasin_nanpa;
+pub mod page;
 pub mod pakala;
+pub mod persist;
 pub mod portfolio;
+pub mod start;
+pub mod tabs;
+pub mod title;
 pub mod tp;
 
 #[component]
 pub fn App() -> impl IntoView {
     provide_meta_context();
+    let desktop = desktop::Desktop::new();
+    provide_context(desktop);
+    provide_context(keep::KeptWindows::new());
+    provide_context(tabs::DetachedTabs::new());
+
+    // shortcuts that work wherever focus is (see SHORTCUTS)
+    let help = create_rw_signal(false);
+    let _ = use_event_listener(use_document(), ev::keydown, move |k| {
+        // (alt + a number switches browser tabs on linux, so workspaces need ctrl too. but
+        // not altgr, which reports ctrl + alt on some systems and types characters)
+        if k.ctrl_key() && k.alt_key() && !k.get_modifier_state("AltGraph") {
+            match workspace_key(&k.code()) {
+                Some(n) if k.shift_key() => desktop.move_active_to(n),
+                Some(n) => desktop.show_workspace(n),
+                None => return,
+            }
+        } else if k.alt_key() {
+            match k.code().as_str() {
+                "Backquote" => desktop.cycle_focus(k.shift_key()),
+                "KeyM" => desktop.minimize_active(),
+                "Enter" => desktop.toggle_expand_active(),
+                "KeyR" => desktop.reopen_last(),
+                _ => return,
+            }
+        } else if k.key() == "?" && !is_typing(&k) {
+            help.update(|h| *h = !*h);
+        } else if k.key() == "Escape" && help.get_untracked() {
+            help.set(false);
+        } else {
+            return;
+        }
+        k.prevent_default();
+    });
+    // the tab is named after the window in front
+    let page_title = move || match desktop.focused() {
+        Some(window) => format!(
+            "{} | etbcor's website",
+            window.title.with_value(|t| t.plain())
+        ),
+        None => "etbcor's website".to_string(),
+    };
+    // don't leave windows stranded off screen when the browser shrinks
+    let _ = use_event_listener(use_window(), ev::resize, move |_| desktop.reflow());
 
     view! {
-        <Title text="etbcor's website"/>
+        <Title text=page_title/>
         <Stylesheet id="leptos" href="/pkg/personal_site.css"/>
 
         // google fonts
@@ -31,22 +92,167 @@ pub fn App() -> impl IntoView {
         // main router
         <Router>
             <main>
-                <Routes>
-                    <Route path="/"               view=home::HomePageWrap/>
-                    <Route path="/portfolio"      view=portfolio::PortfolioPage/>
-                    <Route path="/music"          view=music::MusicPage/>
-                    <Route path="/tp"             view=tp::TokiPonaPage/>
-                    <Route path="/tp/kalama_sin"  view=kalama_sin::KalamaSinPage/>
-                    <Route path="/tp/nasin_nanpa" view=nasin_nanpa::NasinNanpaPage/>
-                    <Route path="/pakala"         view=pakala::PakalaPage/>
-                    <Route path="/*any"           view=NotFoundPage/>
-                </Routes>
+                // (the routes are made from the same table as the start menu and the icons)
+                <Routes children=Box::new(|| Fragment::new(
+                    ROUTES
+                        .iter()
+                        .map(|page| view! { <Route path=page.path view=page.view/> }.into_view())
+                        .collect(),
+                ))/>
+                <keep::KeptWindowLayer/>
+                <tabs::DetachedTabLayer/>
+                <icons::DesktopIcons/>
+                <desktop::TilePreview/>
+                <desktop::SelectionBand/>
+                <KeyboardHelp open=help/>
                 <Cyberpunk/>
             </main>
         </Router>
     }
 }
 
+// whether a key went to something that's being typed into, which gets it instead of the shortcuts
+fn is_typing(e: &web_sys::Event) -> bool {
+    event_target::<web_sys::Element>(e)
+        .closest("input, textarea, [contenteditable]")
+        .is_ok_and(|field| field.is_some())
+}
+
+// the workspace a number key (by its code, like "Digit1") stands for
+fn workspace_key(code: &str) -> Option<usize> {
+    let n = code.strip_prefix("Digit")?.parse::<usize>().ok()?;
+    (1..=desktop::WORKSPACES).contains(&n).then(|| n - 1)
+}
+
+// every keyboard shortcut, as listed by the "?" overlay
+const SHORTCUTS: [(&str, &str); 13] = [
+    ("Alt + `", "focus the next window"),
+    ("Alt + Shift + `", "focus the previous window"),
+    ("Alt + M", "minimize the focused window"),
+    ("Alt + Enter", "expand or restore the focused window"),
+    ("Alt + R", "reopen the last closed window"),
+    ("Ctrl + Alt + 1-4", "show that workspace"),
+    (
+        "Ctrl + Alt + Shift + 1-4",
+        "move the focused window to that workspace",
+    ),
+    ("Arrow keys", "move the window (on its titlebar)"),
+    ("Shift + arrow keys", "resize the window (on its titlebar)"),
+    ("Escape", "restore an expanded window, or close it"),
+    ("Shift + F10", "open the window menu (on its titlebar)"),
+    ("Enter", "press the focused button"),
+    ("?", "show or hide this list"),
+];
+
+#[component]
+fn KeyboardHelp(open: RwSignal<bool>) -> impl IntoView {
+    // take focus while open so escape closes the list rather than the window underneath,
+    // and give it back to whatever had it when the list closes
+    let dialog = create_node_ref::<html::Div>();
+    let had_focus = store_value(None::<web_sys::HtmlElement>);
+    create_effect(move |was_open: Option<bool>| {
+        let is_open = open();
+        if is_open {
+            had_focus.set_value(
+                document()
+                    .active_element()
+                    .and_then(|el| el.dyn_into::<web_sys::HtmlElement>().ok()),
+            );
+            if let Some(dialog) = dialog() {
+                let _ = dialog.focus();
+            }
+        } else if was_open == Some(true) {
+            if let Some(el) = had_focus.get_value().filter(|el| el.is_connected()) {
+                let _ = el.focus();
+            }
+            had_focus.set_value(None);
+        }
+        is_open
+    });
+
+    view! {
+        <div
+            id="keyboard-help"
+            class:hidden=move || !open()
+            on:pointerdown=move |_| open.set(false)
+        >
+            <div role="dialog" aria-modal="true" aria-label="keyboard shortcuts" node_ref=dialog tabindex=-1>
+                <p class="title">"keyboard shortcuts"</p>
+                <table>{
+                    SHORTCUTS
+                        .into_iter()
+                        .map(|(keys, action)| view! {
+                            <tr><td><kbd>{keys}</kbd></td><td>{action}</td></tr>
+                        })
+                        .collect::<Vec<_>>()
+                }</table>
+            </div>
+        </div>
+    }
+}
+
+// a page of the site
+pub struct PageRoute {
+    pub path: &'static str,
+    // its name in the start menu and on its desktop icon, unless it's a hidden page
+    pub label: Option<&'static str>,
+    // its windows, for the start menu
+    pub windows: &'static [page::WindowSpec],
+    view: fn() -> View,
+}
+
+// every route in `App`, in the order they're listed
+pub const ROUTES: [PageRoute; 8] = [
+    PageRoute {
+        path: "/",
+        label: Some("Home"),
+        windows: home::WINDOWS,
+        view: || view! { <home::HomePageWrap/> },
+    },
+    PageRoute {
+        path: "/portfolio",
+        label: Some("Portfolio"),
+        windows: portfolio::WINDOWS,
+        view: || view! { <portfolio::PortfolioPage/> },
+    },
+    PageRoute {
+        path: "/music",
+        label: Some("Music"),
+        windows: music::WINDOWS,
+        view: || view! { <music::MusicPage/> },
+    },
+    PageRoute {
+        path: "/tp",
+        label: Some("toki pona"),
+        windows: tp::WINDOWS,
+        view: || view! { <tp::TokiPonaPage/> },
+    },
+    PageRoute {
+        path: "/tp/kalama_sin",
+        label: Some("kalama sin"),
+        windows: kalama_sin::WINDOWS,
+        view: || view! { <kalama_sin::KalamaSinPage/> },
+    },
+    PageRoute {
+        path: "/tp/nasin_nanpa",
+        label: Some("nasin nanpa"),
+        windows: nasin_nanpa::WINDOWS,
+        view: || view! { <nasin_nanpa::NasinNanpaPage/> },
+    },
+    PageRoute {
+        path: "/pakala",
+        label: None,
+        windows: &[],
+        view: || view! { <pakala::PakalaPage/> },
+    },
+    PageRoute {
+        path: "/*any",
+        label: None,
+        windows: &[],
+        view: || view! { <NotFoundPage/> },
+    },
+];
+
 #[component]
 fn GoatCounter(path: &'static str) -> impl IntoView {
     let settings = format!("{{\"path\": \"{}\"}}", path);
@@ -67,6 +273,55 @@ pub enum WindowContent {
             Vec<(&'static str, HtmlElement<html::Div>)>,
         ),
     ),
+    // tabs that were dragged out of other windows
+    Moved(tabs::TabSet),
+}
+
+// how long windows take to open, close, expand and restore (keep in sync with main.scss)
+const WINDOW_ANIMATION: std::time::Duration = std::time::Duration::from_millis(200);
+// window titlebar + its bottom border
+const TITLEBAR_HEIGHT: u32 = 39;
+// tab titlebar (its border overlaps the content)
+const TAB_TITLEBAR_HEIGHT: u32 = 34;
+
+const RESIZE_HANDLES: [(&str, (i32, i32)); 8] = [
+    ("n", (0, -1)),
+    ("s", (0, 1)),
+    ("e", (1, 0)),
+    ("w", (-1, 0)),
+    ("ne", (1, -1)),
+    ("nw", (-1, -1)),
+    ("se", (1, 1)),
+    ("sw", (-1, 1)),
+];
+
+#[derive(Copy, Clone)]
+struct ResizeStart {
+    dir: (i32, i32),
+    pointer: (i32, i32),
+    pos: (i32, i32),
+    size: (u32, u32),
+}
+
+// moves one edge of a window by `delta` (`dir` is -1 for the left/top edge, 1 for
+// the right/bottom edge, and 0 to leave the axis alone) while keeping the opposite edge in place
+fn resize_edge(pos: i32, len: u32, delta: i32, dir: i32, min: u32, max: Option<u32>) -> (i32, u32) {
+    if dir == 0 {
+        return (pos, len);
+    }
+    let len = len as i32;
+    let new_len = (len + dir * delta)
+        .min(max.map_or(i32::MAX, |m| m as i32))
+        .max(min as i32);
+    let new_pos = if dir < 0 { pos + len - new_len } else { pos };
+    (new_pos, new_len as u32)
+}
+
+// whether an event came from one of the titlebar's buttons rather than the titlebar itself
+fn on_buttons(e: &web_sys::Event) -> bool {
+    event_target::<web_sys::Element>(e)
+        .closest(".win-buttons")
+        .is_ok_and(|b| b.is_some())
 }
 
 #[derive(Copy, Clone)]
@@ -78,20 +333,30 @@ pub enum WindowPos {
 
 #[component]
 fn Window(
-    id: &'static str,
-    title: String,
+    #[prop(into)] id: String,
+    #[prop(into)] title: title::WindowTitle,
     content: WindowContent,
     pos: WindowPos,
     size: RwSignal<(u32, u32)>,
     hidden: RwSignal<bool>,
-    #[prop(default = None)] z_idx: Option<RwSignal<usize>>,
     #[prop(default = true)] expandable: bool,
     #[prop(default = false)] expanded: bool,
+    #[prop(default = true)] resizable: bool,
+    #[prop(default = (150, 70))] min_size: (u32, u32),
+    #[prop(default = None)] max_size: Option<(u32, u32)>,
     #[prop(default = None)] min_button: Option<(RwSignal<bool>, RwSignal<(u32, u32)>)>,
     #[prop(default = false)] diag: bool,
     #[prop(default = false)] scroll: bool,
     #[prop(default = false)] rainbow: bool,
     #[prop(default = false)] diag_tp: bool,
+    #[prop(default = true)] persist: bool,
+    #[prop(default = true)] linkable: bool,
+    #[prop(default = false)] always_on_top: bool,
+    #[prop(default = false)] start_behind: bool,
+    // what the window shows, for "open in new tab"
+    #[prop(default = None)] href: Option<&'static str>,
+    // more entries for the window menu (they go before "Close")
+    #[prop(optional)] extra_menu_items: Vec<menu::MenuItem>,
 ) -> impl IntoView {
     let mut offset = false;
     let pos = match pos {
@@ -105,83 +370,519 @@ fn Window(
     let dpos = create_rw_signal((0, 0));
 
     let expanded = create_rw_signal(expanded);
-    let this_z_idx = create_rw_signal(
-        if id.eq("ad-win") || id.eq("john-win") || !z_idx.is_some() {
-            0
-        } else {
-            z_idx.unwrap().get_untracked()
-        },
-    );
+    let default_layout = (pos.get_untracked(), size.get_untracked());
+    let desktop = expect_context::<desktop::Desktop>();
+    // a page's own windows start on its first workspace, anything opened later on the one showing
+    let workspace = create_rw_signal(if persist {
+        0
+    } else {
+        desktop.workspace.get_untracked()
+    });
+    let narrow = desktop.narrow;
+    // (the single column layout shows every workspace at once)
+    let everywhere = use_context::<desktop::OnEveryWorkspace>().is_some();
+    let away =
+        create_memo(move |_| !everywhere && !narrow() && workspace() != desktop.workspace.get());
+    if persist && !offset {
+        // nested (meta) windows share their signals with the top level ones
+        persist::persist_window(&id, pos, size, hidden, expanded, workspace);
+    }
+    // tabs (and whole windows) can only be dragged into each other on the real desktop, not
+    // in meta windows
+    let merges = !offset && !desktop.is_nested();
+    // tabbed windows keep their tabs in a signal, since tabs can be dragged between windows.
+    // so do plain windows that can be merged, with their content as their only tab
+    let plain = matches!(content, WindowContent::Page(_));
+    let (page_content, tab_set) = match content {
+        WindowContent::Page(content) if merges => {
+            (None, Some(tabs::TabSet::single(&title.plain(), content)))
+        }
+        WindowContent::Page(content) => (Some(content), None),
+        WindowContent::Tabs((active_tab, tabs)) => {
+            (None, Some(tabs::TabSet::new(active_tab, tabs)))
+        }
+        WindowContent::Moved(tabs) => (None, Some(tabs)),
+    };
+    // every page has its own "loading-win" (and so on), so a window kept from another page is
+    // told apart by that page in its element id, its desktop icon and its links
+    let scoped_id = match use_context::<keep::KeptFrom>() {
+        Some(keep::KeptFrom(route)) => format!("kept{}-{id}", route.replace('/', "-")),
+        None => id.clone(),
+    };
+    let titlebar = create_node_ref::<html::Div>();
+    let handle = desktop::WindowHandle {
+        id: store_value(scoped_id.clone()),
+        title: store_value(title.clone()),
+        pos,
+        size,
+        hidden,
+        expanded,
+        on_top: create_rw_signal(always_on_top),
+        workspace,
+        away,
+        titlebar,
+        tabs: tab_set,
+        expandable,
+        resizable,
+        min_size,
+        max_size,
+    };
+    // (`id` stays the window's name in saved layouts and css)
+    let element_id = desktop.scoped_id(&scoped_id);
+    desktop.register(handle, start_behind);
+    let raise = move || desktop.focus(handle);
+    let active = move || desktop.is_active(handle);
+
+    // nested (meta) windows are left out of deep links too
+    if !offset && linkable {
+        let active_tab = tab_set.filter(|_| !plain).map(|set| set.active);
+        let follow_link = move |linked_tab: Option<String>| {
+            hidden.set(false);
+            raise();
+            if let (Some(set), Some(linked_tab)) = (tab_set, linked_tab) {
+                if let Some(linked_tab) = set.tabs.with_untracked(|t| {
+                    t.iter()
+                        .find(|tab| tab.title == linked_tab)
+                        .map(|tab| tab.title)
+                }) {
+                    set.active.set(linked_tab);
+                }
+            }
+        };
+        let link = deep_link::window_link(&scoped_id);
+        if let Some(linked_tab) = link.get_untracked() {
+            follow_link(linked_tab);
+        }
+        // (its first run is after the saved layout is restored, so the link wins over it)
+        create_effect(move |_| {
+            if let Some(linked_tab) = link() {
+                follow_link(linked_tab);
+            }
+        });
+
+        // the url follows the window in front (and its tab)
+        let link_name = deep_link::link_name(&scoped_id).to_string();
+        create_effect(move |was_active: Option<bool>| {
+            let is_active = active();
+            let tab = active_tab.map(|t| t().to_string());
+            if was_active.is_some() && is_active {
+                deep_link::set_params(&[("open", Some(link_name.clone())), ("tab", tab)]);
+            }
+            is_active
+        });
+    }
 
-    let drag = move |e: MouseEvent| {
-        if let Some(z_idx) = z_idx {
-            z_idx.update(|z| *z = *z + 1);
-            this_z_idx.set(z_idx());
+    // windows reopened from the footer (or by a file link) come up on top
+    create_effect(move |was_hidden: Option<bool>| {
+        let is_hidden = hidden();
+        match (was_hidden, is_hidden) {
+            (Some(true), false) => {
+                // (with their own tabs back, if they were dragged somewhere)
+                if let Some(set) = tab_set {
+                    set.reclaim(desktop);
+                }
+                raise();
+            }
+            (Some(false), true) => {
+                // (and tabs from other windows go back to them)
+                if let Some(set) = tab_set {
+                    set.send_home();
+                }
+                desktop.deselect(handle);
+                desktop.remember_closed(handle);
+            }
+            _ => (),
         }
+        is_hidden
+    });
+
+    let moves_tabs = tab_set.is_some() && merges;
+    if let Some(set) = tab_set {
+        // a window that's given away all its tabs closes
+        create_effect(move |_| {
+            if set.tabs.with(|t| t.is_empty()) {
+                hidden.set(true);
+            }
+        });
+        on_cleanup(move || set.forget(desktop));
+    }
+    let detached = expect_context::<tabs::DetachedTabs>();
 
+    // in the single column layout windows fold up like an accordion instead of moving around
+    let collapsed = create_rw_signal(false);
+    let toggle_collapsed = move || collapsed.update(|c| *c = !*c);
+
+    // while it's dragged, a window follows the pointer with a transform (redrawn at most once
+    // a frame) and only moves for real when it's let go
+    let dragging = create_rw_signal(false);
+    let drag_to = store_value(None::<(i32, i32)>);
+    // the latest pointer position (and whether alt is held) that hasn't been drawn yet
+    let drag_pointer = store_value(None::<(i32, i32, bool)>);
+    let drag_frame = store_value(None::<AnimationFrameRequestHandle>);
+    // (part of the window's style, so it survives the style being redrawn mid-drag)
+    let shift = create_rw_signal(None::<(i32, i32)>);
+    let show_shift = move |by: Option<(i32, i32)>| {
+        if shift.get_untracked() != by {
+            shift.set(by);
+        }
+    };
+    let show_drag = move |to: Option<(i32, i32)>| {
         let (x, y) = pos.get_untracked();
-        dpos.set((x - e.client_x(), y - e.client_y()));
-        let drag_cleanup = use_event_listener(document(), ev::mousemove, move |e| {
-            if !expanded.get_untracked() {
-                let (dx, dy) = dpos.get_untracked();
-                pos.set((e.client_x() + dx, e.client_y() + dy))
+        show_shift(to.map(|(tx, ty)| (tx - x, ty - y)));
+    };
+    let drag_frame_done = move || {
+        drag_frame.set_value(None);
+        let Some((px, py, free)) = drag_pointer.get_value() else {
+            return;
+        };
+        drag_pointer.set_value(None);
+        if !dragging.get_untracked() || expanded.get_untracked() {
+            return;
+        }
+        let (dx, dy) = dpos.get_untracked();
+        let next = (px + dx, py + dy);
+        // (a selection of windows can't be tiled or merged, only moved)
+        let group = desktop.in_group(&handle);
+        // a window dropped on another one's titlebar becomes tabs of that one
+        let merge_target = (moves_tabs && !group)
+            .then(|| desktop.titlebar_at(&handle, (px, py)))
+            .flatten();
+        if moves_tabs {
+            desktop.merge_target.set(merge_target);
+        }
+        // nested windows live inside the meta window, so the screen's edges mean nothing to them
+        let to = if desktop.is_nested() {
+            next
+        } else {
+            if resizable {
+                let tile = (merge_target.is_none() && !group).then(|| desktop::tile_rect((px, py)));
+                desktop.tile_preview.set(tile.flatten());
+            }
+            let rect = desktop::Rect {
+                x: next.0,
+                y: next.1,
+                ..handle.outer_rect()
+            };
+            // hold alt to place the window freely
+            let (x, y) = if free {
+                next
+            } else {
+                desktop.snap(&handle, rect)
+            };
+            desktop::keep_reachable(desktop::Rect { x, y, ..rect })
+        };
+        drag_to.set_value(Some(to));
+        show_drag(Some(to));
+        if group {
+            let (x, y) = pos.get_untracked();
+            desktop.group_drag.set(Some((to.0 - x, to.1 - y)));
+        }
+    };
+    // lets go of the window, moving it to wherever it was dragged (or tiling it, with `tile`)
+    let finish_drag = move |tile: bool| {
+        if !dragging.get_untracked() {
+            return;
+        }
+        if let Some(frame) = drag_frame.get_value() {
+            frame.cancel();
+        }
+        // (a move that hasn't been drawn yet still counts)
+        drag_frame_done();
+        dragging.set(false);
+        let merge_target = desktop.merge_target.get_untracked();
+        if moves_tabs {
+            desktop.merge_target.set(None);
+        }
+        // (a merged window stays where it was, for when its tabs come back)
+        if let (Some(set), Some(target), true) = (tab_set, merge_target, tile) {
+            if target.tabs.is_some_and(|to| set.merge_into(to)) {
+                drag_to.set_value(None);
+                show_drag(None);
+                desktop.tile_preview.set(None);
+                desktop.focus(target);
+                return;
+            }
+        }
+        if let Some(to) = drag_to.get_value() {
+            // (the rest of the selection moves as far as this window did)
+            if desktop.in_group(&handle) {
+                let (x, y) = pos.get_untracked();
+                desktop.move_group(&handle, (to.0 - x, to.1 - y));
+            }
+            pos.set(to);
+        }
+        drag_to.set_value(None);
+        show_drag(None);
+        if desktop.group_drag.get_untracked().is_some() {
+            desktop.group_drag.set(None);
+        }
+
+        let preview = desktop.tile_preview.get_untracked();
+        if let (Some(preview), true) = (preview, tile) {
+            pos.set((preview.x, preview.y));
+            size.set(handle.clamp_size(desktop::WindowHandle::size_for(preview)));
+        }
+        desktop.tile_preview.set(None);
+    };
+    on_cleanup(move || {
+        if let Some(frame) = drag_frame.get_value() {
+            frame.cancel();
+        }
+    });
+    // windows shrink into their footer entry when they're closed (or just fade, without one) and
+    // grow back out of it, and settle into place when they're expanded or restored. the classes
+    // that animate it only stay on for as long as it takes, so they don't slow down dragging
+    let animating = create_rw_signal(false);
+    let settling = create_rw_signal(false);
+    let animation_end = store_value(None::<TimeoutHandle>);
+    let animate = move |class: RwSignal<bool>| {
+        if let Some(end) = animation_end.get_value() {
+            end.clear();
+        }
+        class.set(true);
+        let end = move || {
+            animating.set(false);
+            settling.set(false);
+        };
+        animation_end.set_value(set_timeout_with_handle(end, WINDOW_ANIMATION).ok());
+    };
+    // how far the window's middle is from its footer entry's, which it shrinks towards. the
+    // entry is only laid out while the window is closed, so it's measured then and remembered
+    let footer_aim = create_rw_signal(None::<(i32, i32)>);
+    let aim_at_footer = move || {
+        let Some(entry) = desktop
+            .footer_entry(hidden)
+            .filter(|_| !narrow.get_untracked() && !expanded.get_untracked())
+        else {
+            footer_aim.set(None);
+            return;
+        };
+        let to = entry.get_bounding_client_rect();
+        if to.width() == 0.0 {
+            return;
+        }
+        let view = desktop::viewport();
+        let from = handle.outer_rect();
+        let dx = (to.left() + to.width() / 2.0) as i32 + view.x - (from.x + from.w / 2);
+        let dy = (to.top() + to.height() / 2.0) as i32 + view.y - (from.y + from.h / 2);
+        if footer_aim.get_untracked() != Some((dx, dy)) {
+            footer_aim.set(Some((dx, dy)));
+        }
+    };
+    // (by the next frame the window may be gone)
+    let aim_next_frame = move || {
+        request_animation_frame(move || {
+            if footer_aim.try_get_untracked().is_some() {
+                aim_at_footer();
             }
+        })
+    };
+    // (a layout put back when the page loads isn't animated, only what happens after)
+    let settled = store_value(false);
+    create_effect(move |_| {
+        if hidden.get_untracked() {
+            aim_next_frame();
+        }
+        request_animation_frame(move || {
+            let _ = settled.try_set_value(true);
         });
+    });
+    create_effect(move |was: Option<(bool, bool)>| {
+        let now = (hidden(), expanded());
+        match was {
+            Some((was_hidden, _)) if was_hidden != now.0 => {
+                aim_at_footer();
+                // (the entry only shows up once the window's gone)
+                if now.0 {
+                    aim_next_frame();
+                }
+                if settled.get_value() {
+                    animate(animating);
+                }
+            }
+            Some((_, was_expanded)) if was_expanded != now.1 => animate(settling),
+            _ => (),
+        }
+        now
+    });
+    on_cleanup(move || {
+        if let Some(end) = animation_end.get_value() {
+            end.clear();
+        }
+    });
+
+    // the other windows of a dragged selection follow it the same way
+    create_effect(move |_| {
+        let by = desktop.group_drag.get();
+        if !dragging.get_untracked() && desktop.in_group(&handle) {
+            show_shift(by);
+        }
+    });
+
+    let drag_start = move |e: PointerEvent| {
+        raise();
+        if !e.is_primary() || e.button() != 0 || narrow.get_untracked() {
+            return;
+        }
+        // picking up a window that isn't selected drops the selection
+        if !desktop.in_group(&handle) {
+            desktop.clear_selection();
+        }
 
-        let once_opt = UseEventListenerOptions::default();
-        once_opt.once(true);
-        let _ = use_event_listener_with_options(
-            document(),
-            ev::mouseup,
-            move |_| {
-                drag_cleanup();
-            },
-            once_opt,
-        );
+        // capture the pointer so the drag keeps going even if it outruns the titlebar
+        if let Some(titlebar) = titlebar.get_untracked() {
+            let _ = titlebar.set_pointer_capture(e.pointer_id());
+        }
+        let (x, y) = pos.get_untracked();
+        dpos.set((x - e.client_x(), y - e.client_y()));
+        dragging.set(true);
+    };
+    let drag_move = move |e: PointerEvent| {
+        if !dragging.get_untracked() {
+            return;
+        }
+        drag_pointer.set_value(Some((e.client_x(), e.client_y(), e.alt_key())));
+        if drag_frame.with_value(|f| f.is_none()) {
+            drag_frame.set_value(request_animation_frame_with_handle(drag_frame_done).ok());
+        }
+    };
+    let drag_end = move |e: PointerEvent| {
+        // a tap on the titlebar (a scroll cancels the pointer instead)
+        if narrow.get_untracked() && e.type_() == "pointerup" && !on_buttons(&e) {
+            toggle_collapsed();
+        }
+        finish_drag(e.type_() == "pointerup");
     };
 
-    let get_title = move || {
-        if title.starts_with("Loading") {
-            let split: Vec<_> = title.split_whitespace().collect();
-            view! { <p class="title">
-                "Loading "
-                <span style="font-family: 'Cedarville Cursive', cursive; font-size: 12pt; font-style: oblique">{
-                    split[1].to_string()
-                }</span>
-            </p> }
-        } else if title.starts_with("Obtain") {
-            let split: Vec<_> = title.split_whitespace().collect();
-            view! { <p class="title">
-                "Obtain "
-                <span style="font-family: 'Cedarville Cursive', cursive; font-size: 12pt; font-style: oblique">{
-                    split[1].to_string()
-                }</span>
-            </p> }
-        } else if title.starts_with("o pona") {
-            let split: Vec<_> = title.split_whitespace().collect();
-            view! { <p class="title">
-                "o "
-                <span style="font-family: 'Cedarville Cursive', cursive; font-size: 12pt; font-style: oblique">{
-                    split[1].to_string()
-                }</span>
-            </p> }
+    let nudge = move |(dx, dy): (i32, i32)| {
+        let (x, y) = pos.get_untracked();
+        if desktop.is_nested() {
+            pos.set((x + dx, y + dy));
         } else {
-            view! { <p class="title">{&title}</p> }
+            pos.set(desktop::keep_reachable(desktop::Rect {
+                x: x + dx,
+                y: y + dy,
+                ..handle.outer_rect()
+            }));
         }
     };
 
-    let get_pos_size = move || {
-        if !expanded() {
-            format!(
-                "left: {}px; top: {}px; width: {}px; height: {}px; z-index: {}",
-                pos().0,
-                pos().1 + if offset { 35 } else { 0 }, // add space for meta title
-                size().0,
-                size().1 + 39, // add space for title
-                this_z_idx()
-            )
+    let grow = move |(dw, dh): (i32, i32)| {
+        if resizable {
+            let (w, h) = size.get_untracked();
+            size.set(
+                handle.clamp_size(((w as i32 + dw).max(0) as u32, (h as i32 + dh).max(0) as u32)),
+            );
+        }
+    };
+
+    let resizing = create_rw_signal(None::<ResizeStart>);
+    let resize_start = move |dir: (i32, i32), e: PointerEvent| {
+        e.stop_propagation();
+        e.prevent_default();
+        raise();
+        if !e.is_primary() || e.button() != 0 {
+            return;
+        }
+
+        let handle = event_target::<web_sys::Element>(&e);
+        let _ = handle.set_pointer_capture(e.pointer_id());
+        resizing.set(Some(ResizeStart {
+            dir,
+            pointer: (e.client_x(), e.client_y()),
+            pos: pos.get_untracked(),
+            size: size.get_untracked(),
+        }));
+    };
+    let resize_move = move |e: PointerEvent| {
+        if let (Some(start), false) = (resizing.get_untracked(), expanded.get_untracked()) {
+            let (x, w) = resize_edge(
+                start.pos.0,
+                start.size.0,
+                e.client_x() - start.pointer.0,
+                start.dir.0,
+                min_size.0,
+                max_size.map(|m| m.0),
+            );
+            let (y, h) = resize_edge(
+                start.pos.1,
+                start.size.1,
+                e.client_y() - start.pointer.1,
+                start.dir.1,
+                min_size.1,
+                max_size.map(|m| m.1),
+            );
+            pos.set((x, y));
+            size.set((w, h));
+        }
+    };
+    let resize_end = move |_: PointerEvent| resizing.set(None);
+
+    // names the tab list of tabbed windows
+    let title_text = title.plain();
+    // meta windows minimize by going back up a level, everything else goes to the footer
+    let minimize = move || match min_button {
+        Some((deeper, meta_size)) => {
+            deeper.set(false);
+            meta_size.set((200, 437));
+        }
+        None => desktop.close(handle),
+    };
+
+    let menu_open = create_rw_signal(false);
+    let menu_at = create_rw_signal((0, 0));
+    let open_menu = move |at: (i32, i32)| {
+        raise();
+        menu_at.set(at);
+        menu_open.set(true);
+    };
+    let mut menu_items = vec![menu::MenuItem::new("Minimize", minimize)];
+    if expandable {
+        menu_items.push(menu::MenuItem::new(
+            Signal::derive(move || if expanded() { "Restore" } else { "Expand" }),
+            move || expanded.update(|e| *e = !*e),
+        ));
+    }
+    menu_items.push(
+        menu::MenuItem::new("Always on top", move || handle.on_top.update(|t| *t = !*t))
+            .checked(handle.on_top),
+    );
+    menu_items.push(menu::MenuItem::new("Move to default position", move || {
+        expanded.set(false);
+        pos.set(default_layout.0);
+        size.set(default_layout.1);
+    }));
+    if let Some(href) = href {
+        menu_items.push(menu::MenuItem::new("Open in new tab", move || {
+            let _ = window().open_with_url_and_target(href, "_blank");
+        }));
+    }
+    menu_items.extend(extra_menu_items);
+    menu_items.push(menu::MenuItem::new("Close", move || desktop.close(handle)));
+
+    let title_view = view! { <p class="title">{title.view()}</p> };
+
+    // each property is bound on its own, so a drag (which only moves the transform) doesn't
+    // redo the rest of the window's style every frame
+    let placed = move || !narrow() && !expanded();
+    let get_left = move || placed().then(|| format!("{}px", pos().0));
+    // (with space for the meta window's title)
+    let get_top = move || placed().then(|| format!("{}px", pos().1 + if offset { 35 } else { 0 }));
+    // the column decides where windows go, but not how big they are
+    let get_width = move || (!expanded() || narrow()).then(|| format!("{}px", size().0));
+    let get_height = move || {
+        (!(narrow() && collapsed()) && (!expanded() || narrow()))
+            .then(|| format!("{}px", size().1 + TITLEBAR_HEIGHT))
+    };
+    let get_z_index = move || placed().then(|| desktop.z_index(handle).to_string());
+    // where it's dragged to, or (closed) the footer entry it shrinks into
+    let get_transform = move || {
+        if !placed() {
+            None
+        } else if hidden() || away() {
+            footer_aim().map(|(dx, dy)| format!("translate({dx}px, {dy}px) scale(0.1)"))
         } else {
-            "".to_string()
+            shift().map(|(dx, dy)| format!("translate({dx}px, {dy}px)"))
         }
     };
     let get_content_size = move || {
@@ -193,112 +894,271 @@ fn Window(
     };
     let get_tab_size = move || {
         if !expanded() {
-            format!("height: {}px", size().1 - 34)
+            format!("height: {}px", size().1.saturating_sub(TAB_TITLEBAR_HEIGHT))
         } else {
             "".to_string()
         }
     };
 
-    let get_content = match content {
-        WindowContent::Page(content) => view! {
+    let plain_view = move |content: Option<HtmlElement<html::Div>>| {
+        view! {
             <div class="win-content" style=get_content_size class:diag={diag} class:diag-tp={diag_tp} class:scroll={scroll} class:rainbow={rainbow}>
                 { content }
             </div>
-        },
-        WindowContent::Tabs((active_tab, combined_vec)) => {
-            let (titles, tabs): (Vec<_>, Vec<_>) = combined_vec
-                .into_iter()
-                .map(|(title, content)| {
-                    (
-                        view! {
-                            <div
-                                class="title"
-                                class:active=move || active_tab().eq(title)
-                                on:mousedown=move |_| active_tab.set(title)
-                                tabindex=0
-                                on:keydown=move |k| if k.key() == "Enter" { active_tab.set(title) }>
-                                { title }
-                            </div>
-                        },
-                        view! {
-                            <div
-                                class="tab-content"
-                                tabindex=0
-                                class:hidden=move || !active_tab().eq(title)>
-                                { content }
-                            </div>
-                        },
-                    )
-                })
-                .unzip();
+        }
+    };
+    let get_content = if let Some(set) = tab_set {
+        let active_tab = set.active;
+        // a roving tabindex: only the active tab is in the tab order, arrows move between tabs
+        let tab_refs = store_value(Vec::<(&'static str, NodeRef<html::Div>)>::new());
+        let select = move |index: usize| {
+            let Some(title) = set
+                .tabs
+                .with_untracked(|t| t.get(index).map(|tab| tab.title))
+            else {
+                return;
+            };
+            active_tab.set(title);
+            if let Some(tab) = tab_refs
+                .with_value(|t| t.iter().find(|(t, _)| *t == title).map(|(_, tab)| *tab))
+                .and_then(|tab| tab.get_untracked())
+            {
+                let _ = tab.focus();
+            }
+        };
+        let tab_id = {
+            let element_id = store_value(element_id.clone());
+            move |kind: &str, index: usize| {
+                element_id.with_value(|id| format!("{id}-{kind}-{index}"))
+            }
+        };
+        // where each tab is now (tabs come and go as they're dragged between windows)
+        let index_of = move |title: &'static str| {
+            move || {
+                set.tabs
+                    .with(|t| t.iter().position(|tab| tab.title == title))
+                    .unwrap_or(0)
+            }
+        };
 
+        // a tab let go of on another tabbed window's titlebar moves there, and one let go of
+        // anywhere else away from the tabs comes off into a window of its own
+        #[cfg_attr(feature = "ssr", allow(unused_variables))]
+        let tablist = create_node_ref::<html::Div>();
+        let tab_drag_start = move |e: PointerEvent| {
+            if moves_tabs && e.is_primary() && e.button() == 0 && !narrow.get_untracked() {
+                let _ = event_target::<web_sys::Element>(&e).set_pointer_capture(e.pointer_id());
+            }
+        };
+        let tab_drag_end = move |title: &'static str, e: PointerEvent| {
+            let at = (e.client_x(), e.client_y());
+            // (letting go anywhere near the tabs is just a click)
+            if !moves_tabs
+                || !tablist
+                    .get_untracked()
+                    .is_some_and(|t| tabs::is_dragged_off(&t.get_bounding_client_rect(), at))
+            {
+                return;
+            }
+            if let Some(target) = desktop.titlebar_at(&handle, at) {
+                if target.tabs.is_some_and(|to| set.move_tab(title, to)) {
+                    desktop.focus(target);
+                }
+            } else {
+                detached.detach(set, title, at, size.get_untracked());
+            }
+        };
+
+        let title_view = move |tab: tabs::Tab| {
+            let title = tab.title;
+            let index = index_of(title);
+            // node refs only exist in the browser
+            #[cfg_attr(feature = "ssr", allow(unused_variables))]
+            let tab_ref = create_node_ref::<html::Div>();
+            tab_refs.update_value(|t| t.push((title, tab_ref)));
+            on_cleanup(move || tab_refs.update_value(|t| t.retain(|(t, _)| *t != title)));
+            view! {
+                <div
+                    class="title"
+                    id=move || tab_id("tab", index())
+                    role="tab"
+                    aria-controls=move || tab_id("tabpanel", index())
+                    aria-selected=move || active_tab().eq(title).to_string()
+                    class:active=move || active_tab().eq(title)
+                    node_ref=tab_ref
+                    on:pointerdown=move |e| {
+                        active_tab.set(title);
+                        tab_drag_start(e);
+                    }
+                    on:pointerup=move |e| tab_drag_end(title, e)
+                    tabindex=move || if active_tab().eq(title) { 0 } else { -1 }
+                    on:keydown=move |k| {
+                        let (i, count) = (index(), set.count());
+                        let next = match k.key().as_str() {
+                            "ArrowRight" => (i + 1) % count,
+                            "ArrowLeft" => (i + count - 1) % count,
+                            "Home" => 0,
+                            "End" => count - 1,
+                            "Enter" | " " => i,
+                            _ => return,
+                        };
+                        k.prevent_default();
+                        select(next);
+                    }>
+                    { title }
+                </div>
+            }
+        };
+        let panel_view = move |tab: tabs::Tab| {
+            let title = tab.title;
+            let index = index_of(title);
+            view! {
+                <div
+                    class="tab-content"
+                    id=move || tab_id("tabpanel", index())
+                    role="tabpanel"
+                    aria-labelledby=move || tab_id("tab", index())
+                    tabindex=0
+                    class:hidden=move || !active_tab().eq(title)>
+                    { tab.content }
+                </div>
+            }
+        };
+
+        let tabbed_view = move || {
             view! {
                 <div class="win-content" style=get_content_size>
-                    <div class="tab-titlebar">{titles}</div>
-                    <div class="tab-outer" style=get_tab_size class:scroll={scroll} class:diag={diag} class:diag-tp={diag_tp} class:rainbow={rainbow}>{tabs}</div>
+                    <div class="tab-titlebar" role="tablist" aria-label=title_text.clone() node_ref=tablist>
+                        <For each=set.tabs key=|tab| tab.title children=title_view/>
+                    </div>
+                    <div class="tab-outer" style=get_tab_size class:scroll={scroll} class:diag={diag} class:diag-tp={diag_tp} class:rainbow={rainbow}>
+                        <For each=set.tabs key=|tab| tab.title children=panel_view/>
+                    </div>
                 </div>
             }
-        }
+        };
+        // a plain window only grows tabs while it's got some from other windows
+        let shows_tabs = create_memo(move |_| !plain || set.only_own().is_none());
+        (move || {
+            if shows_tabs() {
+                tabbed_view().into_view()
+            } else {
+                plain_view(untrack(|| set.only_own())).into_view()
+            }
+        })
+        .into_view()
+    } else {
+        plain_view(page_content).into_view()
     };
 
     view! {
         <div
-            id=id
+            id=element_id
+            data-win=id
             class="win-outer"
-            style=get_pos_size
-            class:hidden=move || hidden()
+            class:win-active=active
+            aria-current=move || active().then_some("true")
+            style:left=get_left
+            style:top=get_top
+            style:width=get_width
+            style:height=get_height
+            style:z-index=get_z_index
+            style:transform=get_transform
+            class:hidden=move || hidden() || away()
             class:win-expanded=move || expanded()
+            class:win-collapsed=move || narrow() && collapsed()
+            class:win-merge-target=move || desktop.is_merge_target(handle)
+            class:win-selected=move || desktop.is_selected(handle)
+            class:win-animating=animating
+            class:win-settling=settling
         >
             <div
                 class="win-titlebar"
-                on:mousedown=drag
+                node_ref=titlebar
+                on:pointerdown=drag_start
+                on:pointermove=drag_move
+                on:pointerup=drag_end
+                on:pointercancel=drag_end
+                // (in case the capture's lost some other way, like the titlebar going away)
+                on:lostpointercapture=move |_| finish_drag(false)
+                on:contextmenu=move |e| {
+                    e.prevent_default();
+                    open_menu((e.client_x(), e.client_y()));
+                }
                 tabindex=0
+                aria-expanded=move || narrow().then(|| (!collapsed()).to_string())
                 on:keydown=move |k| {
-                    if let Some(z_idx) = z_idx {
-                        z_idx.update(|z| *z = *z + 1);
-                        this_z_idx.set(z_idx());
-                    }
-                    if !expanded() {
+                    raise();
+                    if k.key() == "ContextMenu" || (k.shift_key() && k.key() == "F10") {
+                        if let Some(titlebar) = titlebar.get_untracked() {
+                            let rect = titlebar.get_bounding_client_rect();
+                            open_menu((rect.left() as i32, rect.bottom() as i32));
+                        }
+                        k.prevent_default();
+                    } else if narrow() {
+                        if matches!(k.key().as_str(), "Enter" | " ") && !on_buttons(&k) {
+                            toggle_collapsed();
+                            k.prevent_default();
+                        }
+                    } else if k.key() == "Escape" {
+                        if expanded() { expanded.set(false) } else { desktop.close(handle) }
+                    } else if !expanded() {
+                        // shift+arrows resize instead of moving
+                        let shift = k.shift_key();
+                        let step = move |d| if shift { grow(d) } else { nudge(d) };
                         if match k.key().as_str() {
-                            "ArrowUp" => { pos.update(|(_, a)| *a = *a - 10); true }
-                            "ArrowDown" => { pos.update(|(_, a)| *a = *a + 10); true }
-                            "ArrowLeft" => { pos.update(|(a, _)| *a = *a - 10); true }
-                            "ArrowRight" => { pos.update(|(a, _)| *a = *a + 10); true }
+                            "ArrowUp" => { step((0, -10)); true }
+                            "ArrowDown" => { step((0, 10)); true }
+                            "ArrowLeft" => { step((-10, 0)); true }
+                            "ArrowRight" => { step((10, 0)); true }
                             _ => false,
                         } { k.prevent_default() }
                     }
                 }
             >
-                { get_title }
+                { title_view }
                 <div class="win-buttons">
-                    { match min_button {
-                        Some((deeper, size)) => { Some(view! { <a
-                            class="win-min"
-                            title="minimize window"
-                            on:mousedown=move |_| {deeper.set(false); size.set((200, 437))}
-                            on:keydown=move |k| if k.key() == "Enter" {deeper.set(false); size.set((200, 437))}
-                            tabindex=0
-                        ></a> }) }
-                        None => { None } }
-                    }
+                    { min_button.map(|_| view! { <a
+                        class="win-min"
+                        title="minimize window"
+                        on:pointerdown=move |_| minimize()
+                        on:keydown=move |k| if k.key() == "Enter" { minimize() }
+                        tabindex=0
+                    ></a> }) }
                     { if expandable { Some(view! { <a
                         class="win-expand"
                         title="expand window"
-                        on:mousedown=move |_| expanded.update(|e| *e = !*e)
+                        on:pointerdown=move |_| expanded.update(|e| *e = !*e)
                         on:keydown=move |k| if k.key() == "Enter" { expanded.update(|e| *e = !*e) }
                         tabindex=0
                     ></a> }) } else { None } }
                     <a
                         class="win-close"
                         title="close window"
-                        on:mousedown=move |_| hidden.set(true)
-                        on:keydown=move |k| if k.key() == "Enter" { hidden.set(true) }
+                        on:pointerdown=move |_| desktop.close(handle)
+                        on:keydown=move |k| if k.key() == "Enter" { desktop.close(handle) }
                         tabindex=0
                     ></a>
                 </div>
             </div>
             { get_content }
+            { if resizable { Some(
+                RESIZE_HANDLES
+                    .into_iter()
+                    .map(|(edge, dir)| view! {
+                        <div
+                            class=format!("win-resize win-resize-{edge}")
+                            on:pointerdown=move |e| resize_start(dir, e)
+                            on:pointermove=resize_move
+                            on:pointerup=resize_end
+                            on:pointercancel=resize_end
+                        ></div>
+                    })
+                    .collect::<Vec<_>>()
+            ) } else { None } }
         </div>
+        // outside the window, so it isn't clipped by it
+        <menu::Menu open=menu_open items=menu_items at=menu_at/>
     }
 }
 
@@ -309,37 +1169,119 @@ fn NotFoundPage() -> impl IntoView {
         let resp = expect_context::<leptos_actix::ResponseOptions>();
         resp.set_status(actix_web::http::StatusCode::NOT_FOUND);
     }
-    let loading = create_rw_signal(false);
 
-    view! {
-        <LoadingWindow pos=WindowPos::Val((20, 20)) size=(500, 500) hidden=loading variant=LoadingWindowVariant::PageNotFound/>
-    }
+    page::WindowSlot::new(&NOT_FOUND_WINDOW, FileViewers::new()).view_on_page()
 }
 
+const NOT_FOUND_WINDOW: page::WindowSpec = page::WindowSpec {
+    id: "loading-win",
+    title: &[title::TitlePart::Text("Page Not Found")],
+    label: page::Label::Unlisted,
+    pos: (20, 20),
+    size: (500, 500),
+    flags: page::WindowFlags {
+        expandable: false,
+        rainbow: true,
+        ..page::WindowFlags::DEFAULT
+    },
+    view: |win| {
+        view! { <LoadingWindow win=win variant=LoadingWindowVariant::PageNotFound/> }.into_view()
+    },
+};
+
 #[component]
 fn Footer(
-    items: Vec<(&'static str, RwSignal<bool>)>,
+    items: Vec<(title::WindowTitle, RwSignal<bool>)>,
     #[prop(default = false)] nasa: bool,
+    #[prop(optional)] files: Option<FileViewers>,
 ) -> impl IntoView {
+    let desktop = expect_context::<desktop::Desktop>();
+    let kept = expect_context::<keep::KeptWindows>();
+    let detached = expect_context::<tabs::DetachedTabs>();
+    let narrow = desktop.narrow;
+    // in the single column layout the footer doubles as a list of every window.
+    // (windows the app keeps open show up as its own entries instead of the page's)
+    let item = move |title: title::WindowTitle, hidden: RwSignal<bool>| {
+        // (windows minimize into their entry)
+        let entry = create_node_ref::<html::Div>();
+        desktop.add_footer_entry(hidden, entry);
+        view! {
+            <div
+                class="title win-minimized"
+                node_ref=entry
+                on:pointerdown=move |_| desktop.reveal(hidden)
+                class:hidden=move || (!hidden() && !narrow()) || !desktop.has(hidden)
+                class:win-open=move || !hidden()
+                tabindex=0
+                on:keydown=move |k| if k.key() == "Enter" { desktop.reveal(hidden) }
+                title="open window"
+            >{title.view()}</div>
+        }
+    };
+    let arrange_menu = (!items.is_empty()).then(|| {
+        let open = create_rw_signal(false);
+        let entries = desktop::Arrangement::ALL
+            .into_iter()
+            .map(|arrangement| {
+                menu::MenuItem::new(arrangement.label(), move || desktop.arrange(arrangement))
+            })
+            .collect::<Vec<_>>();
+
+        // (mousedown would move focus to the button, which closes the menu it just opened)
+        view! {
+            <menu::Menu open=open items=entries id="arrange-menu"/>
+            <div
+                class="title win-minimized arrange-button"
+                class:hidden=narrow
+                on:pointerdown=move |_| open.update(|o| *o = !*o)
+                on:mousedown=move |e| e.prevent_default()
+                tabindex=0
+                on:keydown=move |k| if k.key() == "Enter" { open.update(|o| *o = !*o) }
+                title="arrange windows"
+            >"Arrange"</div>
+        }
+    });
+
+    // every page starts out on its first workspace, and can switch to the others
+    desktop.show_workspace(0);
+    let workspaces = (!items.is_empty()).then(|| {
+        (0..desktop::WORKSPACES)
+            .map(|n| {
+                let showing = move || desktop.workspace.get() == n;
+                view! {
+                    <div
+                        class="title win-minimized workspace-button"
+                        class:hidden=narrow
+                        class:win-open=showing
+                        aria-pressed=move || showing().to_string()
+                        on:pointerdown=move |_| desktop.show_workspace(n)
+                        tabindex=0
+                        on:keydown=move |k| if k.key() == "Enter" { desktop.show_workspace(n) }
+                        title=format!("workspace {}", n + 1)
+                    >{n + 1}</div>
+                }
+            })
+            .collect::<Vec<_>>()
+    });
+
     view! {
         <div id="ale-li-pona"></div>
         <div id="nasa-a-a-a" class:hidden={!nasa}></div>
         <footer>
-            {
-                items
-                    .into_iter()
-                    .map(|(title, hidden)| view! {
-                        <div
-                            class="title win-minimized"
-                            on:mousedown=move |_| hidden.set(false)
-                            class:hidden=move || !hidden()
-                            tabindex=0
-                            on:keydown=move |k| if k.key() == "Enter" { hidden.set(false) }
-                            title="open window"
-                        >{title}</div>
-                    })
-                    .collect::<Vec<_>>()
-            }
+            <start::StartMenu/>
+            { arrange_menu }
+            { workspaces }
+            { items.into_iter().map(|(title, hidden)| item(title, hidden)).collect::<Vec<_>>() }
+            <For
+                each=kept.windows()
+                key=|kept| kept.key()
+                children=move |kept| item(kept.win.spec.label().unwrap_or_else(|| kept.win.spec.title()), kept.win.hidden)
+            />
+            { files.map(|files| view! {
+                <For each=files.docs key=|doc| doc.key children=move |doc| item(doc.window_title(), doc.hidden)/>
+            }) }
+            <For each=kept.files().docs key=|doc| doc.key children=move |doc| item(doc.window_title(), doc.hidden)/>
+            <For each=move || detached.windows() key=|win| win.key children=move |win| item(win.title.into(), win.hidden)/>
             <a class="title win-minimized favicon" href="/"></a>
         </footer>
     }
@@ -387,60 +1329,45 @@ enum LoadingWindowVariant {
 }
 
 #[component]
-fn LoadingWindow(
-    pos: WindowPos,
-    size: (u32, u32),
-    hidden: RwSignal<bool>,
-    #[prop(default = None)] z_idx: Option<RwSignal<usize>>,
-    variant: LoadingWindowVariant,
-) -> impl IntoView {
-    let size = create_rw_signal(size);
-
+fn LoadingWindow(win: page::WindowSlot, variant: LoadingWindowVariant) -> impl IntoView {
     let mut rng = rand::thread_rng();
     let noun: &'static str = ABSTRACT_NOUNS.choose(&mut rng).unwrap();
-    let title = {
-        use LoadingWindowVariant::*;
-        match variant {
-            Default => format!("Loading {}", noun),
-            HomePageLink => format!("Obtain {}", noun),
-            PageComingSoon => "Page Coming Soon".to_string(),
-            PageNotFound => "Page Not Found".to_string(),
-            StackOverflow => "Uh-oh! The stack overflowed".to_string(),
-            TP => "o pona".to_string(),
+    // (the ones with a noun pick it when they open, the rest are named by their spec)
+    let title = match variant {
+        LoadingWindowVariant::Default => Some(title::WindowTitle::new("Loading ").cursive(noun)),
+        LoadingWindowVariant::HomePageLink => {
+            Some(title::WindowTitle::new("Obtain ").cursive(noun))
         }
+        _ => None,
     };
 
     let content = WindowContent::Page(view! {
         <div
             class="loading-img"
             class:wait={variant == LoadingWindowVariant::Default}
-            on:mousedown=move |_| leptos_router::use_navigate()(if variant == LoadingWindowVariant::StackOverflow { "/pakala" } else { "/" }, Default::default(),)
+            on:pointerup=move |_| leptos_router::use_navigate()(if variant == LoadingWindowVariant::StackOverflow { "/pakala" } else { "/" }, Default::default(),)
             on:keydown=move |k| if k.key() == "Enter" { leptos_router::use_navigate()(if variant == LoadingWindowVariant::StackOverflow { "/pakala" } else { "/" }, Default::default(),) }
             tabindex=0
             title="ale li pona"
         ></div>
     });
 
-    view! {
-        <Window id="loading-win" title=title content=content pos=pos size=size hidden=hidden expandable=false z_idx=z_idx rainbow=true/>
-    }
+    win.frame_with(
+        content,
+        page::FrameExtras {
+            title,
+            ..Default::default()
+        },
+    )
 }
 
 #[component]
-fn AdWindow(
-    pos: WindowPos,
-    size: (u32, u32),
-    hidden: RwSignal<bool>,
-    #[prop(default = None)] z_idx: Option<RwSignal<usize>>,
-) -> impl IntoView {
-    let size = create_rw_signal(size);
+fn AdWindow(win: page::WindowSlot) -> impl IntoView {
     let content = WindowContent::Page(view! { <div style="cursor: wait">
         <img src="/assets/ur-ad-here.png" draggable="false"/>
     </div> });
 
-    view! {
-        <Window id="ad-win" title="Advertisement".to_string() content=content pos=pos size=size hidden=hidden expandable=false z_idx=z_idx/>
-    }
+    win.frame(content)
 }
 
 enum Webring {
@@ -449,63 +1376,40 @@ enum Webring {
 }
 
 #[component]
-fn WebringWindow(
-    pos: WindowPos,
-    size: (u32, u32),
-    hidden: RwSignal<bool>,
-    #[prop(default = None)] z_idx: Option<RwSignal<usize>>,
-    webring: Webring,
-) -> impl IntoView {
-    let size = create_rw_signal(size);
-    let id = match webring {
-        Webring::Bucket => "bucket-webring-win",
-        Webring::SikePona => "sike-pona-webring-win",
-    };
-    let title = match webring {
-        Webring::Bucket => "Bucket Webring",
-        Webring::SikePona => "sike pona",
-    }
-    .to_string();
-
+fn WebringWindow(win: page::WindowSlot, webring: Webring) -> impl IntoView {
     let content = WindowContent::Page(match webring {
         Webring::Bucket => view! { <div style="margin-left: 16px; margin-right: 16px">
             <iframe
                 src="https://webring.bucketfish.me/embed.html?name=etbcor"
-                id="bucket-webring"
+                class="bucket-webring"
                 style="width: 100%; height: 63px; border: none"
             ></iframe>
         </div> },
         Webring::SikePona => {
+            // (ids, since that's what sike pona's own stylesheet looks for. the pages
+            // it's on never end up in a meta window, so they aren't repeated at every level)
             view! { <div id="sike-pona" style="margin-left: 16px; margin-right: 16px; height: 90%; color: #c8ace5">
                 <link rel="stylesheet" href="https://sike.pona.la/embed.css"/>
                 <span id="left">
                     <a href="https://sike.pona.la/jan/jan%20Itan/prev.html" id="prev">"← prev"</a>
-                    </span>
+                </span>
                 <span id="mid"><a href="https://sike.pona.la">
                     <img class="tokipona" src="https://sike.pona.la/assets/tokipona.svg"></img>
                     "sike pona"
                     <img class="tokipona" src="https://sike.pona.la/assets/tokipona.svg"></img>
                 </a></span>
                 <span id="right">
-                <a href="https://sike.pona.la/jan/jan%20Itan/next.html" id="next">"next →"</a>
+                    <a href="https://sike.pona.la/jan/jan%20Itan/next.html" id="next">"next →"</a>
                 </span>
             </div> }
         }
     });
 
-    view! {
-        <Window id=id title=title content=content pos=pos size=size hidden=hidden expandable=false z_idx=z_idx/>
-    }
+    win.frame(content)
 }
 
 #[component]
-fn JohnWindow(
-    pos: WindowPos,
-    size: (u32, u32),
-    hidden: RwSignal<bool>,
-    #[prop(default = None)] z_idx: Option<RwSignal<usize>>,
-) -> impl IntoView {
-    let size = create_rw_signal(size);
+fn JohnWindow(win: page::WindowSlot) -> impl IntoView {
     let content = WindowContent::Page(view! { <div class="rainbow">
        <iframe
             src="https://john.citrons.xyz/embed?ref=etbcor.com"
@@ -513,41 +1417,23 @@ fn JohnWindow(
         ></iframe>
     </div> });
 
-    view! {
-        <Window id="john-win" title="Johnvertisement".to_string() content=content pos=pos size=size hidden=hidden expandable=false z_idx=z_idx/>
-    }
+    win.frame(content)
 }
 
 #[component]
-fn LonelyWindow(
-    pos: WindowPos,
-    size: (u32, u32),
-    hidden: RwSignal<bool>,
-    #[prop(default = None)] z_idx: Option<RwSignal<usize>>,
-) -> impl IntoView {
-    let size = create_rw_signal(size);
+fn LonelyWindow(win: page::WindowSlot) -> impl IntoView {
     let content = WindowContent::Page(view! { <div tabindex=0>
     </div> });
-    view! {
-        <Window id="lonely-win" title="A bit lonely...".to_string() content=content pos=pos size=size hidden=hidden expandable=false z_idx=z_idx/>
-    }
+    win.frame(content)
 }
 
 #[component]
 fn LinkWindow(
-    pos: WindowPos,
-    size: (u32, u32),
-    hidden: RwSignal<bool>,
-    id: &'static str,
-    title: String,
+    win: page::WindowSlot,
     bg_img: &'static str,
     src: &'static str,
-    #[prop(default = None)] z_idx: Option<RwSignal<usize>>,
-    #[prop(default = false)] diag: bool,
-    #[prop(default = false)] diag_tp: bool,
     #[prop(default = false)] external: bool,
 ) -> impl IntoView {
-    let size = create_rw_signal(size);
     let content = WindowContent::Page(if external {
         view! { <div style="cursor: alias; text-align: center; height: 100%">
             <a href=src target="_blank" style="height: 100%">
@@ -564,16 +1450,20 @@ fn LinkWindow(
                 src=bg_img
                 style="padding: 0px; height: 100%; max-width: 100%"
                 draggable=false
-                on:mousedown=move |_| leptos_router::use_navigate()(src, Default::default())
+                on:pointerup=move |_| leptos_router::use_navigate()(src, Default::default())
                 on:keydown=move |k| if k.key() == "Enter" { leptos_router::use_navigate()(src, Default::default()) }
                 tabindex=0
             />
         </div> }
     });
 
-    view! {
-        <Window id=id title=title content=content pos=pos size=size hidden=hidden expandable=false z_idx=z_idx rainbow={!diag && !diag_tp} diag={diag} diag_tp={diag_tp}/>
-    }
+    win.frame_with(
+        content,
+        page::FrameExtras {
+            href: Some(src),
+            ..Default::default()
+        },
+    )
 }
 
 #[component]
@@ -600,24 +1490,146 @@ fn ExternalLink(
     }
 }
 
-#[component]
-fn FileWindow(
-    pos: WindowPos,
-    size: (u32, u32),
+// the documents opened from a page's file links, each in a viewer window of its own
+#[derive(Copy, Clone)]
+pub struct FileViewers {
+    desktop: desktop::Desktop,
+    // owns the documents, whichever component opens them
+    owner: Owner,
+    docs: RwSignal<Vec<FileDoc>>,
+    next_key: StoredValue<usize>,
+    // starts the ids of the viewer windows
+    prefix: &'static str,
+}
+
+#[derive(Copy, Clone)]
+struct FileDoc {
+    key: usize,
+    src: &'static str,
+    title: &'static str,
     hidden: RwSignal<bool>,
-    #[prop(default = None)] z_idx: Option<RwSignal<usize>>,
-    src: ReadSignal<Option<&'static str>>,
-) -> impl IntoView {
+}
+
+impl FileDoc {
+    fn window_title(&self) -> title::WindowTitle {
+        title::WindowTitle::new(self.title).icon("/assets/file-icon.svg")
+    }
+}
+
+impl FileViewers {
+    pub fn new() -> Self {
+        Self::with_prefix("file-win-")
+    }
+
+    fn with_prefix(prefix: &'static str) -> Self {
+        Self {
+            desktop: expect_context::<desktop::Desktop>(),
+            owner: Owner::current().expect("file viewers belong to a component"),
+            docs: create_rw_signal(Vec::new()),
+            next_key: store_value(0),
+            prefix,
+        }
+    }
+
+    fn is(&self, other: &FileViewers) -> bool {
+        self.docs == other.docs
+    }
+
+    // brings back the viewer showing `src` as `title`, if there is one (two documents
+    // can share a src, like ones that are only placeholders)
+    pub fn reveal(&self, src: &'static str, title: &'static str) -> bool {
+        let Some(doc) = self
+            .docs
+            .with_untracked(|d| d.iter().find(|d| d.src == src && d.title == title).copied())
+        else {
+            return false;
+        };
+        self.desktop.reveal(doc.hidden);
+        true
+    }
+
+    // opens a new viewer for `src`, or (with `reuse`) brings back the one already showing it
+    pub fn open(&self, src: &'static str, title: &'static str, reuse: bool) {
+        if reuse && self.reveal(src, title) {
+            return;
+        }
+        let key = self.next_key.get_value();
+        self.next_key.set_value(key + 1);
+        let hidden = with_owner(self.owner, || create_rw_signal(false));
+        self.docs.update(|d| {
+            d.push(FileDoc {
+                key,
+                src,
+                title,
+                hidden,
+            })
+        });
+    }
+
+    fn close(&self, key: usize) {
+        self.docs.update(|d| d.retain(|doc| doc.key != key));
+    }
+}
+
+impl Default for FileViewers {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// how far each new viewer is shifted from the previous one (starting over every 8 viewers)
+const FILE_WINDOW_STEP: i32 = 30;
+
+#[component]
+fn FileWindows(pos: (i32, i32), size: (u32, u32), files: FileViewers) -> impl IntoView {
+    view! {
+        <For
+            each=files.docs
+            key=|doc| doc.key
+            children=move |doc| {
+                let step = FILE_WINDOW_STEP * (doc.key % 8) as i32;
+                view! { <FileWindow pos=WindowPos::Val((pos.0 + step, pos.1 + step)) size=size files=files doc=doc/> }
+            }
+        />
+    }
+}
+
+#[component]
+fn FileWindow(pos: WindowPos, size: (u32, u32), files: FileViewers, doc: FileDoc) -> impl IntoView {
+    let kept = expect_context::<keep::KeptWindows>();
     let size = create_rw_signal(size);
+    let hidden = doc.hidden;
     let content = WindowContent::Page(view! { <div style="width: 100%; height: 100%">
         <iframe
-            src=move || { if src().is_some() { hidden.set(false); } src().unwrap_or("") }
+            src=doc.src
             allow="autoplay"
             style="width: 100%; height: 100%"></iframe>
     </div> });
 
+    // the viewers link to their document rather than to themselves
+    create_effect(move |_| {
+        if !hidden() {
+            deep_link::set_params(&[("file", Some(doc.src.to_string()))]);
+            persist::remember_file(doc.title, doc.src);
+        } else if deep_link::param("file").as_deref() == Some(doc.src) {
+            deep_link::set_params(&[("file", None)]);
+        }
+    });
+
+    // the viewer's document isn't saved, so neither is its layout
     view! {
-        <Window id="file-win" title="File Viewer".to_string() content=content pos=pos size=size hidden=hidden expanded=true z_idx=z_idx/>
+        <Window
+            id=format!("{}{}", files.prefix, doc.key)
+            title=doc.window_title()
+            content=content
+            pos=pos
+            size=size
+            hidden=hidden
+            persist=false
+            linkable=false
+            href=Some(doc.src)
+            extra_menu_items=vec![kept.file_menu_item(files, doc)]
+        />
     }
 }
 
@@ -625,7 +1637,59 @@ fn FileWindow(
 fn FileLink(
     src: &'static str,
     display: &'static str,
-    file_win_src: WriteSignal<Option<&'static str>>,
+    files: FileViewers,
+    // the viewer's title, if the link text doesn't work as one
+    #[prop(optional)] title: Option<&'static str>,
+    // open another viewer even if this document is already open
+    #[prop(default = false)] new_window: bool,
 ) -> impl IntoView {
-    view! { <a href="" on:mousedown=move |_| file_win_src.set(Some(src)) on:keydown=move |k| if k.key() == "Enter" { file_win_src.set(Some(src)) }>{display}</a> }
+    let title = title.unwrap_or(display);
+    let kept = expect_context::<keep::KeptWindows>();
+    // a document the app keeps open is brought back rather than opened again
+    let show = move |reuse: bool| {
+        if !(reuse && kept.reveal_file(src, title)) {
+            files.open(src, title, reuse);
+        }
+    };
+    let open = move || show(!new_window);
+    // only documents the page links to can be deep linked
+    let linked = deep_link::file_link(src);
+    if linked.get_untracked() {
+        show(true);
+    }
+    create_effect(move |_| {
+        if linked() {
+            show(true);
+        }
+    });
+    view! {
+        <a
+            href=""
+            on:click=move |e| {
+                e.prevent_default();
+                open();
+            }
+        >{display}</a>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resizing_keeps_the_opposite_edge_in_place() {
+        assert_eq!(resize_edge(100, 300, 50, 0, 150, None), (100, 300));
+        assert_eq!(resize_edge(100, 300, 50, 1, 150, None), (100, 350));
+        assert_eq!(resize_edge(100, 300, 50, -1, 150, None), (150, 250));
+        assert_eq!(resize_edge(100, 300, -50, -1, 150, None), (50, 350));
+    }
+
+    #[test]
+    fn resizing_stops_at_min_and_max() {
+        assert_eq!(resize_edge(100, 300, -200, 1, 150, None), (100, 150));
+        assert_eq!(resize_edge(100, 300, 200, -1, 150, None), (250, 150));
+        assert_eq!(resize_edge(100, 300, 200, 1, 150, Some(400)), (100, 400));
+        assert_eq!(resize_edge(100, 300, -200, -1, 150, Some(400)), (0, 400));
+    }
 }
@@ -1,45 +1,94 @@
 use crate::app::{
-    Footer, GoatCounter, JohnWindow, LinkWindow, LoadingWindow, LoadingWindowVariant, Window,
-    WindowContent, WindowPos,
+    page::{Label, Page, WindowFlags, WindowSlot, WindowSpec},
+    title::TitlePart,
+    GoatCounter, JohnWindow, LinkWindow, LoadingWindow, LoadingWindowVariant, WindowContent,
 };
 use leptos::*;
 
+pub const WINDOWS: &[WindowSpec] = &[
+    WindowSpec {
+        id: "loading-win",
+        title: &[TitlePart::Text("\"Inspiration\"")],
+        label: Label::Title,
+        pos: (20, 20),
+        size: (255, 255),
+        flags: WindowFlags {
+            expandable: false,
+            rainbow: true,
+            ..WindowFlags::DEFAULT
+        },
+        view: |win| {
+            view! { <LoadingWindow win=win variant=LoadingWindowVariant::HomePageLink/> }
+                .into_view()
+        },
+    },
+    WindowSpec {
+        id: "my-music-win",
+        title: &[TitlePart::Text("My Music")],
+        label: Label::Title,
+        pos: (20, 347),
+        size: (255, 255),
+        flags: WindowFlags {
+            expandable: false,
+            rainbow: true,
+            ..WindowFlags::DEFAULT
+        },
+        view: |win| {
+            view! { <LinkWindow win=win bg_img="/assets/wireless-nature.png" src="https://mesmr.bandcamp.com" external=true/> }.into_view()
+        },
+    },
+    WindowSpec {
+        id: "spotify-win",
+        title: &[TitlePart::Text("My Public Spotify Playlists")],
+        label: Label::Short("Playlists"),
+        pos: (310, 20),
+        size: (440, 582),
+        flags: WindowFlags {
+            scroll: true,
+            rainbow: true,
+            ..WindowFlags::DEFAULT
+        },
+        view: |win| view! { <SpotifyPlaylistWindow win=win/> }.into_view(),
+    },
+    WindowSpec {
+        id: "john-win",
+        title: &[TitlePart::Text("Johnvertisement")],
+        label: Label::Title,
+        pos: (20, 674),
+        size: (730, 90),
+        flags: WindowFlags {
+            expandable: false,
+            start_behind: true,
+            always_on_top: true,
+            ..WindowFlags::DEFAULT
+        },
+        view: |win| view! { <JohnWindow win=win/> }.into_view(),
+    },
+    // WindowSpec {
+    //     id: "faves-win",
+    //     title: &[TitlePart::Text("My Favorite Music")],
+    //     label: Label::Short("My Favorites"),
+    //     pos: (785, 20),
+    //     size: (440, 746),
+    //     flags: WindowFlags {
+    //         scroll: true,
+    //         rainbow: true,
+    //         ..WindowFlags::DEFAULT
+    //     },
+    //     view: |win| view! { <FavesWindow win=win/> }.into_view(),
+    // },
+];
+
 #[component]
 pub fn MusicPage() -> impl IntoView {
-    let loading_hidden = create_rw_signal(false);
-    let my_music_hidden = create_rw_signal(false);
-    let spotify_hidden = create_rw_signal(false);
-    let john_hidden = create_rw_signal(false);
-    // let faves_hidden = create_rw_signal(false);
-
-    let footer_items = vec![
-        ("\"Inspiration\"", loading_hidden),
-        ("My Music", my_music_hidden),
-        ("Playlists", spotify_hidden),
-        ("Johnvertisement", john_hidden),
-        // ("My Favorites", faves_hidden),
-    ];
-    let z_idx = Some(create_rw_signal(1));
-
     view! {
-        <LoadingWindow         pos=WindowPos::Val((20, 20))  size=(255, 255) hidden=loading_hidden  z_idx=z_idx variant=LoadingWindowVariant::HomePageLink/>
-        <LinkWindow            pos=WindowPos::Val((20, 347)) size=(255, 255) hidden=my_music_hidden z_idx=z_idx id="my-music-win" title="My Music".to_string() bg_img="/assets/wireless-nature.png" src="https://mesmr.bandcamp.com" external=true/>
-        <SpotifyPlaylistWindow pos=WindowPos::Val((310, 20)) size=(440, 582) hidden=spotify_hidden  z_idx=z_idx/>
-        <JohnWindow            pos=WindowPos::Val((20, 674)) size=(730, 90)  hidden=john_hidden     z_idx=z_idx/>
-        // <FavesWindow           pos=WindowPos::Val((785, 20)) size=(440, 746) hidden=faves_hidden    z_idx=z_idx/>
-        <Footer items=footer_items/>
+        <Page windows=WINDOWS/>
         <GoatCounter path="/music"/>
     }
 }
 
 #[component]
-fn SpotifyPlaylistWindow(
-    pos: WindowPos,
-    size: (u32, u32),
-    hidden: RwSignal<bool>,
-    #[prop(default = None)] z_idx: Option<RwSignal<usize>>,
-) -> impl IntoView {
-    let size = create_rw_signal(size);
+fn SpotifyPlaylistWindow(win: WindowSlot) -> impl IntoView {
     let active_tab = create_rw_signal("Main");
     let content = WindowContent::Tabs((
         active_tab,
@@ -98,9 +147,7 @@ fn SpotifyPlaylistWindow(
         ],
     ));
 
-    view! {
-        <Window id="spotify-win" title="My Public Spotify Playlists".to_string() content=content pos=pos size=size hidden=hidden z_idx=z_idx scroll=true rainbow=true/>
-    }
+    win.frame(content)
 }
 
 #[component]
@@ -119,13 +166,7 @@ fn SpotifyPlaylist(src: &'static str, #[prop(default = true)] spaced: bool) -> i
 }
 
 #[component]
-fn FavesWindow(
-    pos: WindowPos,
-    size: (u32, u32),
-    hidden: RwSignal<bool>,
-    #[prop(default = None)] z_idx: Option<RwSignal<usize>>,
-) -> impl IntoView {
-    let size = create_rw_signal(size);
+fn FavesWindow(win: WindowSlot) -> impl IntoView {
     let active_tab = create_rw_signal("Artists");
     let content = WindowContent::Tabs((
         active_tab,
@@ -151,19 +192,11 @@ fn FavesWindow(
         ],
     ));
 
-    view! {
-        <Window id="faves-win" title="My Favorite Music".to_string() content=content pos=pos size=size hidden=hidden z_idx=z_idx scroll=true rainbow=true/>
-    }
+    win.frame(content)
 }
 
 #[component]
-pub fn MusicLinkWindow(
-    pos: WindowPos,
-    size: (u32, u32),
-    hidden: RwSignal<bool>,
-    #[prop(default = None)] z_idx: Option<RwSignal<usize>>,
-) -> impl IntoView {
-    let size = create_rw_signal(size);
+pub fn MusicLinkWindow(win: WindowSlot) -> impl IntoView {
     let content = WindowContent::Page(view! { <div style="cursor: pointer">
         <video
             style="width: 100%"
@@ -171,7 +204,7 @@ pub fn MusicLinkWindow(
             autoplay
             loop="true"
             poster="/assets/music-icon.png"
-            on:mousedown=move |_| leptos_router::use_navigate()("/music", Default::default())
+            on:pointerup=move |_| leptos_router::use_navigate()("/music", Default::default())
             on:contextmenu=move |e| e.prevent_default()
             tabindex=0
             on:keydown=move |k| if k.key() == "Enter" { leptos_router::use_navigate()("/music", Default::default()) }
@@ -180,7 +213,5 @@ pub fn MusicLinkWindow(
         </video>
     </div> });
 
-    view! {
-        <Window id="music-link-win" title="Music".to_string() content=content pos=pos size=size hidden=hidden expandable=false z_idx=z_idx rainbow=true/>
-    }
+    win.frame(content)
 }
@@ -1,45 +1,90 @@
 use crate::app::{
-    ExternalLink, Footer, GoatCounter, LinkWindow, LoadingWindow, LoadingWindowVariant, Webring,
-    WebringWindow, Window, WindowContent, WindowPos,
+    page::{Label, Page, WindowFlags, WindowSlot, WindowSpec},
+    title::TitlePart,
+    ExternalLink, GoatCounter, LinkWindow, LoadingWindow, LoadingWindowVariant, Webring,
+    WebringWindow, WindowContent,
 };
 use leptos::*;
 
+pub const WINDOWS: &[WindowSpec] = &[
+    WindowSpec {
+        id: "tp-link-win",
+        title: &[TitlePart::Text("lipu pi toki pona")],
+        label: Label::Title,
+        pos: (20, 20),
+        size: (255, 255),
+        flags: WindowFlags {
+            expandable: false,
+            diag_tp: true,
+            ..WindowFlags::DEFAULT
+        },
+        view: |win| view! { <LinkWindow win=win bg_img="/assets/itan.svg" src="/tp"/> }.into_view(),
+    },
+    WindowSpec {
+        id: "nasin-nanpa-link-win",
+        title: &[TitlePart::Text("lon ilo GitHub")],
+        label: Label::Title,
+        pos: (310, 20),
+        size: (620, 255),
+        flags: WindowFlags {
+            expandable: false,
+            rainbow: true,
+            ..WindowFlags::DEFAULT
+        },
+        view: |win| {
+            view! { <LinkWindow win=win bg_img="/assets/nasin-nanpa-github-screenshot.png" src="https://github.com/ETBCOR/nasin-nanpa" external=true/> }.into_view()
+        },
+    },
+    WindowSpec {
+        id: "nasin-nanpa-win",
+        title: &[TitlePart::SitelenPona("nasin sitelen tan anpa nanpa")],
+        label: Label::Short("nasin nanpa"),
+        pos: (20, 347),
+        size: (910, 255),
+        flags: WindowFlags {
+            scroll: true,
+            ..WindowFlags::DEFAULT
+        },
+        view: |win| view! { <NasinNanpaWindow win=win/> }.into_view(),
+    },
+    WindowSpec {
+        id: "sike-pona-webring-win",
+        title: &[TitlePart::Text("sike pona")],
+        label: Label::Title,
+        pos: (20, 674),
+        size: (720, 70),
+        flags: WindowFlags {
+            expandable: false,
+            resizable: false,
+            ..WindowFlags::DEFAULT
+        },
+        view: |win| view! { <WebringWindow win=win webring=Webring::SikePona/> }.into_view(),
+    },
+    WindowSpec {
+        id: "loading-win",
+        title: &[TitlePart::Text("o "), TitlePart::Cursive("pona")],
+        label: Label::Title,
+        pos: (775, 674),
+        size: (155, 70),
+        flags: WindowFlags {
+            expandable: false,
+            rainbow: true,
+            ..WindowFlags::DEFAULT
+        },
+        view: |win| view! { <LoadingWindow win=win variant=LoadingWindowVariant::TP/> }.into_view(),
+    },
+];
+
 #[component]
 pub fn NasinNanpaPage() -> impl IntoView {
-    let tp_hidden = create_rw_signal(false);
-    let link_hidden = create_rw_signal(false);
-    let nasin_nanpa_hidden = create_rw_signal(false);
-    let webring_hidden = create_rw_signal(false);
-    let loading_hidden = create_rw_signal(false);
-
-    let footer_items = vec![
-        ("lipu pi toki pona", tp_hidden),
-        ("lon ilo Github", link_hidden),
-        ("nasin nanpa", nasin_nanpa_hidden),
-        ("sike pona", webring_hidden),
-        ("o pona", loading_hidden),
-    ];
-    let z_idx = Some(create_rw_signal(1));
-
     view! {
-        <LinkWindow       pos=WindowPos::Val((20, 20))   size=(255, 255) hidden=tp_hidden          z_idx=z_idx id="tp-link-win"        title="lipu pi toki pona".to_string() bg_img="/assets/itan.svg"            src="/tp" diag_tp=true/>
-        <LinkWindow       pos=WindowPos::Val((310, 20))  size=(620, 255) hidden=link_hidden z_idx=z_idx id="nasin-nanpa-link-win" title="lon ilo GitHub".to_string() bg_img="/assets/nasin-nanpa-github-screenshot.png" src="https://github.com/ETBCOR/nasin-nanpa" external=true/>
-        <NasinNanpaWindow pos=WindowPos::Val((20, 347))  size=(910, 255) hidden=nasin_nanpa_hidden z_idx=z_idx/>
-        <WebringWindow    pos=WindowPos::Val((20, 674))  size=(720, 70)  hidden=webring_hidden     z_idx=z_idx webring=Webring::SikePona/>
-        <LoadingWindow    pos=WindowPos::Val((775, 674)) size=(155, 70)  hidden=loading_hidden     z_idx=z_idx variant=LoadingWindowVariant::TP/>
-        <Footer items=footer_items/>
+        <Page windows=WINDOWS/>
         <GoatCounter path="/tp/nasin_nanpa"/>
     }
 }
 
 #[component]
-fn NasinNanpaWindow(
-    pos: WindowPos,
-    size: (u32, u32),
-    hidden: RwSignal<bool>,
-    #[prop(default = None)] z_idx: Option<RwSignal<usize>>,
-) -> impl IntoView {
-    let size = create_rw_signal(size);
+fn NasinNanpaWindow(win: WindowSlot) -> impl IntoView {
     let active_tab = create_rw_signal("Font Versions");
 
     let content = WindowContent::Tabs((
@@ -143,7 +188,5 @@ fn NasinNanpaWindow(
         ],
     ));
 
-    view! {
-        <Window id="nasin-nanpa-win" title="nasin sitelen tan anpa nanpa".to_string() content=content pos=pos size=size hidden=hidden z_idx=z_idx scroll=true/>
-    }
+    win.frame(content)
 }
@@ -0,0 +1,214 @@
+use crate::app::{
+    desktop::Desktop,
+    keep::KeptWindows,
+    menu::MenuItem,
+    title::{TitlePart, WindowTitle},
+    FileViewers, FileWindows, Footer, Window, WindowContent, WindowPos,
+};
+use leptos::*;
+use leptos_router::use_location;
+
+// how a window behaves, apart from what it shows
+#[derive(Copy, Clone)]
+pub struct WindowFlags {
+    pub expandable: bool,
+    pub resizable: bool,
+    pub scroll: bool,
+    pub rainbow: bool,
+    pub diag: bool,
+    pub diag_tp: bool,
+    // whether its layout is saved (see persist.rs)
+    pub persist: bool,
+    // opens under the other windows instead of on top of them
+    pub start_behind: bool,
+    // stays above the windows that aren't (the user can change it from the window menu)
+    pub always_on_top: bool,
+    // how far it can be resized
+    pub min_size: (u32, u32),
+    pub max_size: Option<(u32, u32)>,
+}
+
+impl WindowFlags {
+    pub const DEFAULT: Self = Self {
+        expandable: true,
+        resizable: true,
+        scroll: false,
+        rainbow: false,
+        diag: false,
+        diag_tp: false,
+        persist: true,
+        start_behind: false,
+        always_on_top: false,
+        min_size: (150, 70),
+        max_size: None,
+    };
+}
+
+// how a window is listed in the footer and the start menu
+#[derive(Copy, Clone)]
+pub enum Label {
+    Title,
+    // a shorter name than its title
+    Short(&'static str),
+    Unlisted,
+}
+
+// one of a page's windows. pages list theirs in the order they're stacked (bottom
+// first, except for `start_behind` ones), which is also the order of the footer
+#[derive(Copy, Clone)]
+pub struct WindowSpec {
+    pub id: &'static str,
+    pub title: &'static [TitlePart],
+    pub label: Label,
+    pub pos: (i32, i32),
+    pub size: (u32, u32),
+    pub flags: WindowFlags,
+    // makes the window, usually with `WindowSlot::frame`
+    pub view: fn(WindowSlot) -> View,
+}
+
+impl WindowSpec {
+    pub fn title(&self) -> WindowTitle {
+        WindowTitle::of(self.title)
+    }
+
+    // its entry in the footer and the start menu, if it has one
+    pub fn label(&self) -> Option<WindowTitle> {
+        match self.label {
+            Label::Title => Some(self.title()),
+            Label::Short(label) => Some(label.into()),
+            Label::Unlisted => None,
+        }
+    }
+}
+
+// what a window has on top of its spec, for the ones that need more than `WindowSlot::frame`
+#[derive(Default)]
+pub struct FrameExtras {
+    // made when the window opens, instead of the spec's
+    pub title: Option<WindowTitle>,
+    // what the window shows, for "open in new tab"
+    pub href: Option<&'static str>,
+    // for windows that change their own size
+    pub size: Option<RwSignal<(u32, u32)>>,
+    pub min_button: Option<(RwSignal<bool>, RwSignal<(u32, u32)>)>,
+}
+
+// a spec put on a page, with the signals the rest of the page uses to control it
+#[derive(Copy, Clone)]
+pub struct WindowSlot {
+    pub spec: &'static WindowSpec,
+    pub pos: WindowPos,
+    pub hidden: RwSignal<bool>,
+    pub files: FileViewers,
+}
+
+impl WindowSlot {
+    pub fn new(spec: &'static WindowSpec, files: FileViewers) -> Self {
+        Self {
+            spec,
+            pos: WindowPos::Val(spec.pos),
+            hidden: create_rw_signal(false),
+            files,
+        }
+    }
+
+    pub fn view(self) -> View {
+        (self.spec.view)(self)
+    }
+
+    // the window, unless the app is keeping it open (and showing it) already
+    pub fn view_on_page(self) -> impl Fn() -> Option<View> {
+        let kept = expect_context::<KeptWindows>();
+        let id = self.spec.id;
+        let route = use_location().pathname.get_untracked();
+        let kept = create_memo(move |_| kept.is_kept(&route, id));
+        move || (!kept()).then(|| self.view())
+    }
+
+    // what the spec adds to the window menu
+    pub fn menu_items(self) -> Vec<MenuItem> {
+        // (windows in meta windows belong to the page that's showing them)
+        let nested = expect_context::<Desktop>().is_nested();
+        match self.pos {
+            WindowPos::OffsetSignal(_) => Vec::new(),
+            _ if nested => Vec::new(),
+            _ => vec![expect_context::<KeptWindows>().menu_item(self.spec)],
+        }
+    }
+
+    // the window the spec describes, around `content`
+    pub fn frame(self, content: WindowContent) -> View {
+        self.frame_with(content, FrameExtras::default())
+    }
+
+    pub fn frame_with(self, content: WindowContent, extras: FrameExtras) -> View {
+        let WindowSpec {
+            id, size, flags, ..
+        } = *self.spec;
+        view! {
+            <Window
+                id=id
+                title=extras.title.unwrap_or_else(|| self.spec.title())
+                content=content
+                pos=self.pos
+                size=extras.size.unwrap_or_else(|| create_rw_signal(size))
+                hidden=self.hidden
+                expandable=flags.expandable
+                resizable=flags.resizable
+                scroll=flags.scroll
+                rainbow=flags.rainbow
+                diag=flags.diag
+                diag_tp=flags.diag_tp
+                persist=flags.persist
+                start_behind=flags.start_behind
+                always_on_top=flags.always_on_top
+                min_size=flags.min_size
+                max_size=flags.max_size
+                href=extras.href
+                min_button=extras.min_button
+                extra_menu_items=self.menu_items()
+            />
+        }
+        .into_view()
+    }
+}
+
+pub fn footer_items(slots: &[WindowSlot]) -> Vec<(WindowTitle, RwSignal<bool>)> {
+    slots
+        .iter()
+        .filter_map(|win| win.spec.label().map(|label| (label, win.hidden)))
+        .collect()
+}
+
+// a page made from its windows' specs: the windows, the file viewers (if the page
+// has somewhere for them) and the footer
+#[component]
+pub fn Page(
+    windows: &'static [WindowSpec],
+    // where file viewers open, and how big they are
+    #[prop(optional)] file_windows: Option<((i32, i32), (u32, u32))>,
+    #[prop(default = false)] nasa: bool,
+    // anything else the page has, between the windows and the footer
+    #[prop(optional)] children: Option<Children>,
+) -> impl IntoView {
+    let files = FileViewers::new();
+    if file_windows.is_some() {
+        expect_context::<KeptWindows>().set_page_files(files);
+    }
+    let slots = windows
+        .iter()
+        .map(|spec| WindowSlot::new(spec, files))
+        .collect::<Vec<_>>();
+    let items = footer_items(&slots);
+    // once the windows have put their saved layouts back
+    let desktop = expect_context::<Desktop>();
+    create_effect(move |_| request_animation_frame(move || desktop.reflow()));
+
+    view! {
+        { slots.into_iter().map(WindowSlot::view_on_page).collect::<Vec<_>>() }
+        { file_windows.map(|(pos, size)| view! { <FileWindows pos=pos size=size files=files/> }) }
+        { children.map(|children| children()) }
+        <Footer items=items nasa=nasa files=files/>
+    }
+}
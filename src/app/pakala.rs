@@ -1,4 +1,4 @@
-use crate::app::{Footer, GoatCounter};
+use crate::app::{page::Page, GoatCounter};
 use leptos::*;
 
 const MESSAGES: [&str; 12] = [
@@ -20,8 +20,6 @@ const MESSAGES: [&str; 12] = [
 pub fn PakalaPage() -> impl IntoView {
     let chat_hidden = create_rw_signal(false);
 
-    let footer_items = vec![];
-
     let msg = create_rw_signal(MESSAGES[0]);
     let msg_idx: RwSignal<usize> = create_rw_signal(0);
     let next_msg = move || {
@@ -37,21 +35,22 @@ pub fn PakalaPage() -> impl IntoView {
     };
 
     view! {
-        <div style="background-color: black; position: absolute; top: 0px; bottom: 0px; left: 0px; right: 0px; z-index: -3"></div>
-        <div id="nanpa-suli">
-            <video
-                muted
-                autoplay
-                loop="true"
-                poster="/assets/nanpa-suli.png"
-                on:contextmenu=move |e| e.prevent_default()>
-                <source src="/assets/nanpa-suli.webm" type="video/webm"/>
-            </video>
-        </div>
-        <div id="chat-bubble" class:hidden=move || chat_hidden() on:mousedown=move |_| next_msg() on:keydown=move |k| if k.key() == "Enter" { next_msg() } tabindex=0><div>
-            { move || msg() }
-        </div></div>
-        <Footer items=footer_items nasa=true/>
+        <Page windows={&[]} nasa=true>
+            <div style="background-color: black; position: absolute; top: 0px; bottom: 0px; left: 0px; right: 0px; z-index: -3"></div>
+            <div id="nanpa-suli">
+                <video
+                    muted
+                    autoplay
+                    loop="true"
+                    poster="/assets/nanpa-suli.png"
+                    on:contextmenu=move |e| e.prevent_default()>
+                    <source src="/assets/nanpa-suli.webm" type="video/webm"/>
+                </video>
+            </div>
+            <div id="chat-bubble" class:hidden=move || chat_hidden() on:pointerdown=move |_| next_msg() on:keydown=move |k| if k.key() == "Enter" { next_msg() } tabindex=0><div>
+                { move || msg() }
+            </div></div>
+        </Page>
         <GoatCounter path="/pakala"/>
     }
 }
@@ -0,0 +1,276 @@
+use crate::app::desktop::WORKSPACES;
+use leptos::*;
+use leptos_router::use_location;
+
+// bump this whenever the saved format changes (stale entries get thrown out)
+const LAYOUT_VERSION: u32 = 2;
+
+#[derive(Copy, Clone, PartialEq)]
+pub struct WindowLayout {
+    pub pos: (i32, i32),
+    pub size: (u32, u32),
+    pub hidden: bool,
+    pub expanded: bool,
+    pub workspace: usize,
+}
+
+impl WindowLayout {
+    fn encode(&self) -> String {
+        format!(
+            "{},{},{},{},{},{},{}",
+            self.pos.0,
+            self.pos.1,
+            self.size.0,
+            self.size.1,
+            self.hidden,
+            self.expanded,
+            self.workspace
+        )
+    }
+
+    fn decode(s: &str) -> Option<Self> {
+        let parts: Vec<_> = s.split(',').collect();
+        if parts.len() != 7 {
+            return None;
+        }
+        Some(Self {
+            pos: (parts[0].parse().ok()?, parts[1].parse().ok()?),
+            size: (parts[2].parse().ok()?, parts[3].parse().ok()?),
+            hidden: parts[4].parse().ok()?,
+            expanded: parts[5].parse().ok()?,
+            workspace: parts[6].parse().ok()?,
+        })
+    }
+}
+
+fn storage_key(route: &str, id: &str) -> String {
+    format!("win:{route}:{id}")
+}
+
+fn local_storage() -> Option<web_sys::Storage> {
+    window().local_storage().ok().flatten()
+}
+
+// a stored entry: the version, the defaults it was saved against and the saved value
+fn entry(defaults: &str, saved: &str) -> String {
+    format!("{LAYOUT_VERSION}|{defaults}|{saved}")
+}
+
+// entries saved under another version or against other defaults (i.e. the
+// page's default layout changed since) are stale
+fn saved_in<'a>(entry: &'a str, defaults: &str) -> Option<&'a str> {
+    let mut parts = entry.splitn(3, '|');
+    let version = parts.next()?.parse::<u32>().ok();
+    let saved_defaults = parts.next();
+    if version != Some(LAYOUT_VERSION) || saved_defaults != Some(defaults) {
+        return None;
+    }
+    parts.next()
+}
+
+// (stale entries get thrown out)
+fn load(key: &str, defaults: &str) -> Option<String> {
+    let storage = local_storage()?;
+    let entry = storage.get_item(key).ok()??;
+    let saved = saved_in(&entry, defaults).map(str::to_string);
+    if saved.is_none() {
+        let _ = storage.remove_item(key);
+    }
+    saved
+}
+
+fn save(key: &str, defaults: &str, saved: &str) {
+    if let Some(storage) = local_storage() {
+        let _ = storage.set_item(key, &entry(defaults, saved));
+    }
+}
+
+fn forget(key: &str) {
+    if let Some(storage) = local_storage() {
+        let _ = storage.remove_item(key);
+    }
+}
+
+// restores a window's layout (keyed by route + window id) once the page has
+// hydrated, then keeps the saved copy up to date as the window changes
+pub fn persist_window(
+    id: &str,
+    pos: RwSignal<(i32, i32)>,
+    size: RwSignal<(u32, u32)>,
+    hidden: RwSignal<bool>,
+    expanded: RwSignal<bool>,
+    workspace: RwSignal<usize>,
+) {
+    let key = storage_key(&use_location().pathname.get_untracked(), id);
+    let defaults = WindowLayout {
+        pos: pos.get_untracked(),
+        size: size.get_untracked(),
+        hidden: hidden.get_untracked(),
+        expanded: expanded.get_untracked(),
+        workspace: workspace.get_untracked(),
+    };
+    let encoded_defaults = defaults.encode();
+
+    // effects only run in the browser, so the server always renders the defaults
+    create_effect(move |restored: Option<()>| {
+        let layout = WindowLayout {
+            pos: pos(),
+            size: size(),
+            hidden: hidden(),
+            expanded: expanded(),
+            workspace: workspace(),
+        };
+        if restored.is_none() {
+            if let Some(saved) =
+                load(&key, &encoded_defaults).and_then(|saved| WindowLayout::decode(&saved))
+            {
+                pos.set(saved.pos);
+                size.set(saved.size);
+                hidden.set(saved.hidden);
+                expanded.set(saved.expanded);
+                workspace.set(saved.workspace.min(WORKSPACES - 1));
+            }
+        } else if layout == defaults {
+            forget(&key);
+        } else {
+            save(&key, &encoded_defaults, &layout.encode());
+        }
+    });
+}
+
+fn icon_key(route: &str, id: &str) -> String {
+    format!("icon:{route}:{id}")
+}
+
+fn encode_pos((x, y): (i32, i32)) -> String {
+    format!("{x},{y}")
+}
+
+fn decode_pos(s: &str) -> Option<(i32, i32)> {
+    let (x, y) = s.split_once(',')?;
+    Some((x.parse().ok()?, y.parse().ok()?))
+}
+
+// restores a desktop icon's position (keyed by route + icon) once the page has
+// hydrated, then saves it whenever it's moved. like windows, a saved position is
+// thrown out when the icon's default position changes
+pub fn persist_icon(id: &str, pos: RwSignal<(i32, i32)>) {
+    let key = icon_key(&use_location().pathname.get_untracked(), id);
+    let defaults = encode_pos(pos.get_untracked());
+
+    create_effect(move |restored: Option<()>| {
+        let current = encode_pos(pos());
+        if restored.is_none() {
+            if let Some(saved) = load(&key, &defaults).and_then(|saved| decode_pos(&saved)) {
+                pos.set(saved);
+            }
+        } else if current == defaults {
+            forget(&key);
+        } else {
+            save(&key, &defaults, &current);
+        }
+    });
+}
+
+const RECENT_FILES_KEY: &str = "recent-files";
+// how many documents the start menu remembers
+const RECENT_FILES: usize = 8;
+
+#[derive(Clone)]
+pub struct RecentFile {
+    pub route: String,
+    pub title: String,
+    pub src: String,
+}
+
+// documents opened in a file viewer, most recent first
+pub fn recent_files() -> Vec<RecentFile> {
+    let Some(entries) = local_storage().and_then(|s| s.get_item(RECENT_FILES_KEY).ok().flatten())
+    else {
+        return Vec::new();
+    };
+    entries
+        .lines()
+        .filter_map(|entry| {
+            let mut parts = entry.splitn(3, '|');
+            Some(RecentFile {
+                route: parts.next()?.to_string(),
+                title: parts.next()?.to_string(),
+                src: parts.next()?.to_string(),
+            })
+        })
+        .collect()
+}
+
+pub fn remember_file(title: &str, src: &str) {
+    let Some(storage) = local_storage() else {
+        return;
+    };
+    let route = use_location().pathname.get_untracked();
+    let mut recent = recent_files();
+    recent.retain(|f| f.src != src);
+    recent.insert(
+        0,
+        RecentFile {
+            route,
+            // titles can't break the format
+            title: title.replace(['|', '\n'], " "),
+            src: src.to_string(),
+        },
+    );
+    recent.truncate(RECENT_FILES);
+
+    let entries = recent
+        .iter()
+        .map(|f| format!("{}|{}|{}", f.route, f.title, f.src))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let _ = storage.set_item(RECENT_FILES_KEY, &entries);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LAYOUT: WindowLayout = WindowLayout {
+        pos: (-20, 345),
+        size: (400, 300),
+        hidden: true,
+        expanded: false,
+        workspace: 2,
+    };
+
+    #[test]
+    fn layouts_survive_encoding() {
+        assert!(WindowLayout::decode(&LAYOUT.encode()) == Some(LAYOUT));
+        assert!(WindowLayout::decode("1,2,3,4,true,false").is_none());
+        assert!(WindowLayout::decode("1,2,3,-4,true,false,0").is_none());
+        assert!(WindowLayout::decode("1,2,3,4,yes,false,0").is_none());
+    }
+
+    #[test]
+    fn stale_entries_are_ignored() {
+        let defaults = WindowLayout {
+            hidden: false,
+            ..LAYOUT
+        }
+        .encode();
+        let saved = LAYOUT.encode();
+        let stored = entry(&defaults, &saved);
+        assert_eq!(saved_in(&stored, &defaults), Some(saved.as_str()));
+
+        // the page's default layout changed
+        let moved = WindowLayout {
+            pos: (20, 20),
+            ..LAYOUT
+        }
+        .encode();
+        assert_eq!(saved_in(&stored, &moved), None);
+
+        // saved by an older version
+        let old = format!("{}|{defaults}|{saved}", LAYOUT_VERSION - 1);
+        assert_eq!(saved_in(&old, &defaults), None);
+        assert_eq!(saved_in(&saved, &defaults), None);
+        assert_eq!(saved_in("", &defaults), None);
+    }
+}
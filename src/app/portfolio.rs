@@ -1,51 +1,99 @@
 use crate::app::{
-    AdWindow, ExternalLink, FileLink, FileWindow, Footer, GoatCounter, LoadingWindow,
-    LoadingWindowVariant, Window, WindowContent, WindowPos,
+    page::{Label, Page, WindowFlags, WindowSlot, WindowSpec},
+    title::TitlePart,
+    AdWindow, ExternalLink, FileLink, GoatCounter, LoadingWindow, LoadingWindowVariant,
+    WindowContent,
 };
 use leptos::*;
 
+pub const WINDOWS: &[WindowSpec] = &[
+    WindowSpec {
+        id: "loading-win",
+        title: &[TitlePart::Text("\"Inspiration\"")],
+        label: Label::Title,
+        pos: (435, 204),
+        size: (225, 202),
+        flags: WindowFlags {
+            expandable: false,
+            rainbow: true,
+            ..WindowFlags::DEFAULT
+        },
+        view: |win| {
+            view! { <LoadingWindow win=win variant=LoadingWindowVariant::HomePageLink/> }
+                .into_view()
+        },
+    },
+    WindowSpec {
+        id: "about-win",
+        title: &[TitlePart::Text("About Me")],
+        label: Label::Title,
+        pos: (20, 20),
+        size: (640, 112),
+        flags: WindowFlags::DEFAULT,
+        view: |win| view! { <AboutWindow win=win/> }.into_view(),
+    },
+    WindowSpec {
+        id: "education-win",
+        title: &[TitlePart::Text("Education")],
+        label: Label::Title,
+        pos: (20, 204),
+        size: (380, 572),
+        flags: WindowFlags::DEFAULT,
+        view: |win| view! { <EducationWindow win=win/> }.into_view(),
+    },
+    WindowSpec {
+        id: "projects-win",
+        title: &[TitlePart::Text("Projects")],
+        label: Label::Title,
+        pos: (435, 478),
+        size: (810, 298),
+        flags: WindowFlags {
+            scroll: true,
+            ..WindowFlags::DEFAULT
+        },
+        view: |win| view! { <ProjectsWindow win=win/> }.into_view(),
+    },
+    WindowSpec {
+        id: "skills-win",
+        title: &[TitlePart::Text("Skills")],
+        label: Label::Title,
+        pos: (695, 20),
+        size: (550, 386),
+        flags: WindowFlags {
+            scroll: true,
+            ..WindowFlags::DEFAULT
+        },
+        view: |win| view! { <SkillsWindow win=win/> }.into_view(),
+    },
+    WindowSpec {
+        id: "ad-win",
+        title: &[TitlePart::Text("Advertisement")],
+        label: Label::Unlisted,
+        pos: (100, 600),
+        size: (200, 100),
+        flags: WindowFlags {
+            expandable: false,
+            resizable: false,
+            start_behind: true,
+            always_on_top: true,
+            ..WindowFlags::DEFAULT
+        },
+        view: |win| view! { <AdWindow win=win/> }.into_view(),
+    },
+];
+
 #[component]
 pub fn PortfolioPage() -> impl IntoView {
-    let loading_hidden = create_rw_signal(false);
-    let about_hidden = create_rw_signal(false);
-    let education_hidden = create_rw_signal(false);
-    let skills_hidden = create_rw_signal(false);
-    let projects_hidden = create_rw_signal(false);
-    let file_hidden = create_rw_signal(true);
-    let ad_hidden = create_rw_signal(false);
-
-    let footer_items = vec![
-        ("\"Inspiration\"", loading_hidden),
-        ("About Me", about_hidden),
-        ("Education", education_hidden),
-        ("Projects", projects_hidden),
-        ("Skills", skills_hidden),
-    ];
-    let (file_src, set_file_src) = create_signal(None);
-    let z_idx = create_rw_signal(1);
-
     view! {
-        <LoadingWindow   pos=WindowPos::Val((435, 204)) size=(225, 202) hidden=loading_hidden   z_idx=Some(z_idx) variant=LoadingWindowVariant::HomePageLink/>
-        <AboutWindow     pos=WindowPos::Val((20, 20))   size=(640, 112) hidden=about_hidden     z_idx=Some(z_idx)/>
-        <EducationWindow pos=WindowPos::Val((20, 204))  size=(380, 572) hidden=education_hidden z_idx=Some(z_idx)/>
-        <SkillsWindow    pos=WindowPos::Val((695, 20))  size=(550, 386) hidden=skills_hidden    z_idx=Some(z_idx)/>
-        <ProjectsWindow  pos=WindowPos::Val((435, 478)) size=(810, 298) hidden=projects_hidden  z_idx=Some(z_idx) file_win_src=set_file_src/>
-        <FileWindow      pos=WindowPos::Val((1278, 20)) size=(500, 756) hidden=file_hidden      z_idx=Some(z_idx) src=file_src/>
-        <AdWindow        pos=WindowPos::Val((100, 600)) size=(200, 100) hidden=ad_hidden        z_idx=Some(z_idx)/>
-        <div style="height: 65px"></div> // spacer in narrow view
-        <Footer items=footer_items/>
+        <Page windows=WINDOWS file_windows=((1278, 20), (500, 756))>
+            <div style="height: 65px"></div> // spacer in narrow view
+        </Page>
         <GoatCounter path="/portfolio"/>
     }
 }
 
 #[component]
-fn AboutWindow(
-    pos: WindowPos,
-    size: (u32, u32),
-    hidden: RwSignal<bool>,
-    #[prop(default = None)] z_idx: Option<RwSignal<usize>>,
-) -> impl IntoView {
-    let size = create_rw_signal(size);
+fn AboutWindow(win: WindowSlot) -> impl IntoView {
     let content = WindowContent::Page(view! { <div style="padding: 5px" tabindex=0><p>
         "Hello! I'm Ethan (born April 2001). "
         "Some of my passions include: making music, making software, and learning languages. Links: "
@@ -56,19 +104,11 @@ fn AboutWindow(
         <span class="title">"jan Itan"</span><i>" (toki pona community)"</i>". "<b>"Thanks for coming to my site!"</b>
     </p> </div> });
 
-    view! {
-        <Window id="about-win" title="About Me".to_string() content=content pos=pos size=size hidden=hidden z_idx=z_idx/>
-    }
+    win.frame(content)
 }
 
 #[component]
-fn EducationWindow(
-    pos: WindowPos,
-    size: (u32, u32),
-    hidden: RwSignal<bool>,
-    #[prop(default = None)] z_idx: Option<RwSignal<usize>>,
-) -> impl IntoView {
-    let size = create_rw_signal(size);
+fn EducationWindow(win: WindowSlot) -> impl IntoView {
     let content = WindowContent::Page(view! { <div style="padding: 5px" tabindex=0>
         <h4>"Bachelor's Degree in Computer Science"</h4>
         <div class="spaced">
@@ -116,19 +156,11 @@ fn EducationWindow(
         "."</p>
     </div> });
 
-    view! {
-        <Window id="education-win" title="Education".to_string() content=content pos=pos size=size hidden=hidden z_idx=z_idx/>
-    }
+    win.frame(content)
 }
 
 #[component]
-fn SkillsWindow(
-    pos: WindowPos,
-    size: (u32, u32),
-    hidden: RwSignal<bool>,
-    #[prop(default = None)] z_idx: Option<RwSignal<usize>>,
-) -> impl IntoView {
-    let size = create_rw_signal(size);
+fn SkillsWindow(win: WindowSlot) -> impl IntoView {
     let active_tab = create_rw_signal("Technical");
 
     let content = WindowContent::Tabs((
@@ -229,21 +261,12 @@ fn SkillsWindow(
         ],
     ));
 
-    view! {
-        <Window id="skills-win" title="Skills".to_string() content=content pos=pos size=size hidden=hidden z_idx=z_idx scroll=true/>
-    }
+    win.frame(content)
 }
 
 #[component]
-fn ProjectsWindow(
-    pos: WindowPos,
-    size: (u32, u32),
-    hidden: RwSignal<bool>,
-    #[prop(default = None)] z_idx: Option<RwSignal<usize>>,
-    file_win_src: WriteSignal<Option<&'static str>>,
-) -> impl IntoView {
-    let size = create_rw_signal(size);
-    let fws = file_win_src;
+fn ProjectsWindow(win: WindowSlot) -> impl IntoView {
+    let files = win.files;
     let active_tab = create_rw_signal("From CS Classes");
 
     let content = WindowContent::Tabs((
@@ -256,20 +279,20 @@ fn ProjectsWindow(
                         <ExternalLink href="https://catalog.uidaho.edu/courses/cs/#:~:text=CS%20415" display="CS415 | Computational Biology: Sequence Alignment" bold=true/><br/>
                         "Description: \"Design and analyze algorithms that address the computational problems posed by biological sequence data, "
                         "such as DNA or protein sequences.\""<br/>"Projects:"<br/>
-                        <FileLink src="https://drive.google.com/file/d/17M8KI3B6rCj2_WLL-YlbxBoK0WzTyexO/preview" display="GA Simulation Runner" file_win_src=fws/>
+                        <FileLink src="https://drive.google.com/file/d/17M8KI3B6rCj2_WLL-YlbxBoK0WzTyexO/preview" display="GA Simulation Runner" files=files/>
                         " | "<ExternalLink href="https://github.com/ETBCOR/cs415/tree/main/project01" display="Github Repository"/>
                         <br/>
-                        <FileLink src="https://drive.google.com/file/d/1v9XjTqRlf4iGjHskT7yp_KUyVBUU7WgE/preview" display="Parameter Set Estimation" file_win_src=fws/>
+                        <FileLink src="https://drive.google.com/file/d/1v9XjTqRlf4iGjHskT7yp_KUyVBUU7WgE/preview" display="Parameter Set Estimation" files=files/>
                         " | "<ExternalLink href="https://colab.research.google.com/drive/1zQtt-kDBhycueP_qyhzc9VnFeZe0wPmu?usp=sharing" display="Colab Notebook"/>
                         <br/>
-                        <FileLink src="https://drive.google.com/file/d/1n-nyTQzjcGy9lpTvs-WYdBcTaDUbZfap/preview" display="Pairwise Alignment Matrix Calculation" file_win_src=fws/>
+                        <FileLink src="https://drive.google.com/file/d/1n-nyTQzjcGy9lpTvs-WYdBcTaDUbZfap/preview" display="Pairwise Alignment Matrix Calculation" files=files/>
                         " | "<ExternalLink href="https://colab.research.google.com/drive/1mMGnMO63KR-wHriGNYxBxF5YNwk_r7AP?usp=sharing" display="Colab Notebook"/>
                     </li>
 
                     <li class="spaced">
                         <ExternalLink href="https://catalog.uidaho.edu/courses/cs/#:~:text=CS%20445" display="CS445 | Compiler Design" bold=true/><br/>
                         "In "<ExternalLink href="http://www2.cs.uidaho.edu/~mdwilder/cs445/" display="this class"/>" I fully implemented a compiler for the "<span style="white-space: nowrap">"\"C minus\""</span>" langauge (grammar specification "
-                        <FileLink src="https://drive.google.com/file/d/12o5aSATedS28eJwsHIOHR7uf3DdZY20V/preview" display="here" file_win_src=fws/>
+                        <FileLink src="https://drive.google.com/file/d/12o5aSATedS28eJwsHIOHR7uf3DdZY20V/preview" display="here" title="C minus Grammar Specification" files=files/>
                         "). This is probably the largest solo project I've completed so far. Repository "
                         <ExternalLink href="https://github.com/ETBCOR/cs445" display="here"/>"."
                     </li>
@@ -283,16 +306,16 @@ fn ProjectsWindow(
                     <li class="spaced">
                         <ExternalLink href="https://catalog.uidaho.edu/courses/cs/#:~:text=CS%20470" display="CS470 | Artificial Intelligence" bold=true/><br/>
                         "This class taugh common concepts and techniques involved in artificial intelligence. Projects:"<br/>
-                        <FileLink src="https://drive.google.com/file/d/1ICaQOsGKwJ7RfE21xBHvozQkfQGkw43G/preview" display="Pathfinding Algorithms" file_win_src=fws/>
+                        <FileLink src="https://drive.google.com/file/d/1ICaQOsGKwJ7RfE21xBHvozQkfQGkw43G/preview" display="Pathfinding Algorithms" files=files/>
                         " | "<ExternalLink href="https://github.com/ETBCOR/cs470/tree/master/proj1" display="Github Repository"/>
                         <br/>
-                        <FileLink src="https://drive.google.com/file/d/1fK-F2X7uwnOk8CrDosopO1pRl6xlBc1u/preview" display="Connect-4 Bot Using Minmax" file_win_src=fws/>
+                        <FileLink src="https://drive.google.com/file/d/1fK-F2X7uwnOk8CrDosopO1pRl6xlBc1u/preview" display="Connect-4 Bot Using Minmax" files=files/>
                         " | "<ExternalLink href="https://github.com/ETBCOR/cs470/tree/master/proj2" display="Github Repository"/>
                         <br/>
-                        <FileLink src="https://drive.google.com/file/d/1Qr5B0yZ8s3aY3Ywdd4KCYq_7y5bXfCTg/preview" display="Map Coloring Algorithms" file_win_src=fws/>
+                        <FileLink src="https://drive.google.com/file/d/1Qr5B0yZ8s3aY3Ywdd4KCYq_7y5bXfCTg/preview" display="Map Coloring Algorithms" files=files/>
                         " | "<ExternalLink href="https://github.com/ETBCOR/cs470/tree/master/proj3" display="Github Repository"/>
                         <br/>
-                        <FileLink src="https://drive.google.com/file/d/1ysXZTxxRYNOqZDYkrTWZj6VWc2TndJZR/preview" display="Modeling Genealogy in Prolog" file_win_src=fws/>
+                        <FileLink src="https://drive.google.com/file/d/1ysXZTxxRYNOqZDYkrTWZj6VWc2TndJZR/preview" display="Modeling Genealogy in Prolog" files=files/>
                     </li>
 
                     <li class="spaced">
@@ -340,7 +363,5 @@ fn ProjectsWindow(
         ],
     ));
 
-    view! {
-        <Window id="projects-win" title="Projects".to_string() content=content pos=pos size=size hidden=hidden z_idx=z_idx scroll=true/>
-    }
+    win.frame(content)
 }
@@ -0,0 +1,200 @@
+use crate::app::{deep_link, persist, ROUTES};
+use leptos::*;
+use leptos_router::{use_navigate, ParamsMap};
+use web_sys::wasm_bindgen::JsCast;
+
+#[derive(Clone, PartialEq)]
+struct StartEntry {
+    label: String,
+    // the page (or the recent documents) it's listed under
+    group: String,
+    href: String,
+    nested: bool,
+}
+
+fn link(route: &str, params: &[(&str, &str)]) -> String {
+    let mut query = ParamsMap::new();
+    for (key, value) in params {
+        query.insert(key.to_string(), value.to_string());
+    }
+    format!("{route}{}", query.to_query_string())
+}
+
+fn entries(recent: &[persist::RecentFile]) -> Vec<StartEntry> {
+    let mut entries = Vec::new();
+    for (route, page) in ROUTES
+        .iter()
+        .filter_map(|route| route.label.map(|page| (route, page)))
+    {
+        entries.push(StartEntry {
+            label: page.to_string(),
+            group: page.to_string(),
+            href: route.path.to_string(),
+            nested: false,
+        });
+        entries.extend(route.windows.iter().filter_map(|spec| {
+            spec.label().map(|label| StartEntry {
+                label: label.plain(),
+                group: page.to_string(),
+                href: link(route.path, &[("open", deep_link::link_name(spec.id))]),
+                nested: true,
+            })
+        }));
+    }
+    entries.extend(recent.iter().map(|file| StartEntry {
+        label: file.title.clone(),
+        group: "Recent documents".to_string(),
+        href: link(&file.route, &[("file", &file.src)]),
+        nested: true,
+    }));
+    entries
+}
+
+// an entry matches when it (or the page it's on) contains what's been typed
+fn matches(entry: &StartEntry, filter: &str) -> bool {
+    let filter = filter.to_lowercase();
+    entry.label.to_lowercase().contains(&filter) || entry.group.to_lowercase().contains(&filter)
+}
+
+// a start button for the footer, with a menu of every page, its windows and
+// the documents opened recently. typing filters the list, arrows move through it
+#[component]
+pub fn StartMenu() -> impl IntoView {
+    let open = create_rw_signal(false);
+    let filter = create_rw_signal(String::new());
+    let active = create_rw_signal(0_usize);
+    let recent = create_rw_signal(Vec::new());
+
+    let shown = create_memo(move |_| {
+        recent.with(|recent| {
+            filter.with(|filter| {
+                entries(recent)
+                    .into_iter()
+                    .filter(|e| matches(e, filter))
+                    .collect::<Vec<_>>()
+            })
+        })
+    });
+
+    let input = create_node_ref::<html::Input>();
+    let toggle = move || {
+        if open.get_untracked() {
+            open.set(false);
+            return;
+        }
+        // (local storage isn't there on the server, so it's read when the menu opens)
+        recent.set(persist::recent_files());
+        filter.set(String::new());
+        active.set(0);
+        open.set(true);
+        if let Some(input) = input.get_untracked() {
+            let _ = input.focus();
+        }
+    };
+    let go = move |index: usize| {
+        if let Some(entry) = shown.with_untracked(|s| s.get(index).cloned()) {
+            open.set(false);
+            use_navigate()(&entry.href, Default::default());
+        }
+    };
+
+    let on_keydown = move |k: ev::KeyboardEvent| {
+        let count = shown.with_untracked(|s| s.len()).max(1);
+        let current = active.get_untracked();
+        match k.key().as_str() {
+            "ArrowDown" => active.set((current + 1) % count),
+            "ArrowUp" => active.set((current + count - 1) % count),
+            "Enter" => go(current),
+            "Escape" => open.set(false),
+            _ => return,
+        }
+        k.prevent_default();
+        k.stop_propagation();
+        // keep the active entry in view
+        if let Some(entry) =
+            document().get_element_by_id(&format!("start-entry-{}", active.get_untracked()))
+        {
+            entry.scroll_into_view_with_bool(false);
+        }
+    };
+
+    // like menus, it closes once focus goes somewhere else
+    let menu = create_node_ref::<html::Div>();
+    let focus_out = move |e: ev::FocusEvent| {
+        let inside = match (menu.get_untracked(), e.related_target()) {
+            (Some(menu), Some(to)) => menu.contains(Some(to.unchecked_ref())),
+            _ => false,
+        };
+        if !inside {
+            open.set(false);
+        }
+    };
+
+    let list = move || {
+        shown.with(|shown| {
+            shown
+                .iter()
+                .enumerate()
+                .map(|(i, entry)| {
+                    // a heading wherever a new group starts
+                    let heading = (entry.nested && (i == 0 || shown[i - 1].group != entry.group))
+                        .then(|| view! { <div class="start-heading" aria-hidden="true">{entry.group.clone()}</div> });
+                    view! {
+                        {heading}
+                        <div
+                            id=format!("start-entry-{i}")
+                            class="title start-entry"
+                            class:start-nested=entry.nested
+                            class:start-active=move || active() == i
+                            role="option"
+                            aria-selected=move || (active() == i).to_string()
+                            on:pointerdown=move |e| {
+                                e.prevent_default();
+                                go(i);
+                            }
+                            on:pointermove=move |_| active.set(i)
+                        >{entry.label.clone()}</div>
+                    }
+                })
+                .collect::<Vec<_>>()
+        })
+    };
+
+    view! {
+        <div id="start-menu" class:hidden=move || !open() node_ref=menu on:focusout=focus_out>
+            <input
+                type="text"
+                placeholder="type to search"
+                role="combobox"
+                aria-label="search pages and windows"
+                aria-expanded=move || open().to_string()
+                aria-controls="start-list"
+                // (there's nothing to point at when nothing matches)
+                aria-activedescendant=move || {
+                    (!shown.with(|s| s.is_empty())).then(|| format!("start-entry-{}", active()))
+                }
+                node_ref=input
+                prop:value=filter
+                on:input=move |e| {
+                    filter.set(event_target_value(&e));
+                    active.set(0);
+                }
+                on:keydown=on_keydown
+            />
+            <div id="start-list" role="listbox">
+                {list}
+                {move || shown.with(|s| s.is_empty()).then(|| view! { <div class="start-heading">"nothing found"</div> })}
+            </div>
+        </div>
+        // (mousedown would move focus to the button, which closes the menu it just opened)
+        <div
+            class="title win-minimized start-button"
+            on:pointerdown=move |_| toggle()
+            on:mousedown=move |e| e.prevent_default()
+            tabindex=0
+            on:keydown=move |k| if k.key() == "Enter" { toggle() }
+            title="start menu"
+            aria-haspopup="listbox"
+        >"Start"</div>
+    }
+}
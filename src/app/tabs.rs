@@ -0,0 +1,294 @@
+use crate::app::{
+    desktop::{Desktop, OnEveryWorkspace},
+    Window, WindowContent, WindowPos,
+};
+use leptos::*;
+use std::{cell::RefCell, collections::HashSet};
+
+// how far (in px) a tab has to be dragged away from its window's tabs before it comes off
+const DETACH_DISTANCE: f64 = 30.0;
+// where a detached window opens, relative to the pointer
+const DETACH_OFFSET: (i32, i32) = (-60, -15);
+
+// a tab of a tabbed window. tabs can be dragged from one window to another, so each
+// one remembers the window it was declared in (its "home")
+#[derive(Clone)]
+pub struct Tab {
+    pub title: &'static str,
+    pub content: HtmlElement<html::Div>,
+    home: TabSet,
+}
+
+// the tabs a window is showing, and which one is in front. tabs are told apart by
+// their titles, so a window can't take a tab with the same title as one it has
+#[derive(Copy, Clone)]
+pub struct TabSet {
+    pub active: RwSignal<&'static str>,
+    pub tabs: RwSignal<Vec<Tab>>,
+    // made by detaching a tab, so none of the tabs are its own
+    detached: bool,
+}
+
+impl TabSet {
+    pub fn new(
+        active: RwSignal<&'static str>,
+        tabs: Vec<(&'static str, HtmlElement<html::Div>)>,
+    ) -> Self {
+        let set = Self::empty(active, false);
+        set.tabs.set_untracked(
+            tabs.into_iter()
+                .map(|(title, content)| Tab {
+                    title,
+                    content,
+                    home: set,
+                })
+                .collect(),
+        );
+        set
+    }
+
+    // a plain window, whose content is its only tab until it's given others
+    pub fn single(title: &str, content: HtmlElement<html::Div>) -> Self {
+        let title = intern(title);
+        Self::new(create_rw_signal(title), vec![(title, content)])
+    }
+
+    fn empty(active: RwSignal<&'static str>, detached: bool) -> Self {
+        Self {
+            active,
+            tabs: create_rw_signal(Vec::new()),
+            detached,
+        }
+    }
+
+    pub fn is(&self, other: &TabSet) -> bool {
+        self.tabs == other.tabs
+    }
+
+    pub fn count(&self) -> usize {
+        self.tabs.with_untracked(|t| t.len())
+    }
+
+    // a detached window whose tabs have all gone elsewhere, which is never coming back
+    pub fn is_leftover(&self) -> bool {
+        self.detached && self.tabs.with(|t| t.is_empty())
+    }
+
+    // the content of the window's own tab, if that's the only one it has
+    pub fn only_own(&self) -> Option<HtmlElement<html::Div>> {
+        let me = *self;
+        self.tabs.with(|t| match t.as_slice() {
+            [tab] if tab.home.is(&me) => Some(tab.content.clone()),
+            _ => None,
+        })
+    }
+
+    fn has(&self, title: &str) -> bool {
+        self.tabs
+            .with_untracked(|t| t.iter().any(|tab| tab.title == title))
+    }
+
+    // takes out the tabs `which` picks (leaving another tab in front, if the front one went)
+    fn take_where(&self, which: impl Fn(&Tab) -> bool) -> Vec<Tab> {
+        let mut taken = Vec::new();
+        self.tabs.try_update(|t| {
+            let (gone, kept) = std::mem::take(t).into_iter().partition(|tab| which(tab));
+            taken = gone;
+            *t = kept;
+        });
+        let active = self.active.try_get_untracked();
+        if active.is_some_and(|active| taken.iter().any(|tab| tab.title == active)) {
+            if let Some(first) = self.tabs.with_untracked(|t| t.first().map(|tab| tab.title)) {
+                self.active.set(first);
+            }
+        }
+        taken
+    }
+
+    fn put(&self, tabs: Vec<Tab>) {
+        self.tabs.try_update(|t| t.extend(tabs));
+    }
+
+    // moves every tab into `to`, keeping the one in front in front
+    pub fn merge_into(&self, to: TabSet) -> bool {
+        if self.is(&to)
+            || self
+                .tabs
+                .with_untracked(|t| t.iter().any(|tab| to.has(tab.title)))
+        {
+            return false;
+        }
+        let active = self.active.get_untracked();
+        to.put(self.take_where(|_| true));
+        to.active.set(active);
+        true
+    }
+
+    // moves the tab `title` into `to` and shows it there
+    pub fn move_tab(&self, title: &'static str, to: TabSet) -> bool {
+        if self.is(&to) || to.has(title) {
+            return false;
+        }
+        to.put(self.take_where(|tab| tab.title == title));
+        to.active.set(title);
+        true
+    }
+
+    // sends the tabs that came from other windows back to them
+    pub fn send_home(&self) {
+        let me = *self;
+        for tab in self.take_where(|tab| !tab.home.is(&me)) {
+            let home = tab.home;
+            home.put(vec![tab]);
+        }
+    }
+
+    // brings this window's own tabs back from wherever they were dragged
+    pub fn reclaim(&self, desktop: Desktop) {
+        let me = *self;
+        for set in desktop.tab_sets().into_iter().filter(|set| !set.is(&me)) {
+            self.put(set.take_where(|tab| tab.home.is(&me)));
+        }
+        if !self.has(self.active.get_untracked()) {
+            if let Some(first) = self.tabs.with_untracked(|t| t.first().map(|tab| tab.title)) {
+                self.active.set(first);
+            }
+        }
+    }
+
+    // takes this window's own tabs out of every other window, for when it goes away
+    pub fn forget(&self, desktop: Desktop) {
+        let me = *self;
+        for set in desktop.tab_sets().into_iter().filter(|set| !set.is(&me)) {
+            set.take_where(|tab| tab.home.is(&me));
+        }
+    }
+}
+
+// tabs are told apart by `&'static str` titles, but a plain window's title is only made
+// when it opens. each different title is kept for good (there are only so many of them)
+fn intern(title: &str) -> &'static str {
+    thread_local! {
+        static TITLES: RefCell<HashSet<&'static str>> = RefCell::new(HashSet::new());
+    }
+    TITLES.with(|titles| {
+        let mut titles = titles.borrow_mut();
+        match titles.get(title) {
+            Some(title) => title,
+            None => {
+                let title: &'static str = Box::leak(title.into());
+                titles.insert(title);
+                title
+            }
+        }
+    })
+}
+
+// whether a tab let go of at `at` is far enough from its window's tabs (at `tabs`) to come off
+pub fn is_dragged_off(tabs: &web_sys::DomRect, (x, y): (i32, i32)) -> bool {
+    let (x, y) = (x as f64, y as f64);
+    x < tabs.left() - DETACH_DISTANCE
+        || x > tabs.right() + DETACH_DISTANCE
+        || y < tabs.top() - DETACH_DISTANCE
+        || y > tabs.bottom() + DETACH_DISTANCE
+}
+
+// windows made from tabs dragged out of other windows. like kept windows they belong to
+// the app, but what's in them still belongs to the page it came from
+#[derive(Copy, Clone)]
+pub struct DetachedTabs {
+    owner: Owner,
+    windows: RwSignal<Vec<DetachedWindow>>,
+    next_key: StoredValue<usize>,
+}
+
+#[derive(Copy, Clone)]
+pub struct DetachedWindow {
+    pub key: usize,
+    pub title: &'static str,
+    tabs: TabSet,
+    pos: (i32, i32),
+    size: (u32, u32),
+    pub hidden: RwSignal<bool>,
+}
+
+impl DetachedTabs {
+    pub fn new() -> Self {
+        Self {
+            owner: Owner::current().expect("detached tabs belong to the app"),
+            windows: create_rw_signal(Vec::new()),
+            next_key: store_value(0),
+        }
+    }
+
+    // the detached windows that still have tabs
+    pub fn windows(&self) -> Vec<DetachedWindow> {
+        self.windows.with(|w| {
+            w.iter()
+                .filter(|win| !win.tabs.is_leftover())
+                .copied()
+                .collect()
+        })
+    }
+
+    // moves the tab `title` out of `from` into a window of its own, under the pointer at `at`
+    pub fn detach(&self, from: TabSet, title: &'static str, at: (i32, i32), size: (u32, u32)) {
+        if from.count() < 2 {
+            return;
+        }
+        // (windows that have given all their tabs back are done with. `from` isn't one of them)
+        self.windows
+            .update(|w| w.retain(|win| !win.tabs.tabs.with_untracked(|t| t.is_empty())));
+        let key = self.next_key.get_value();
+        self.next_key.set_value(key + 1);
+        let (tabs, hidden) = with_owner(self.owner, || {
+            (
+                TabSet::empty(create_rw_signal(title), true),
+                create_rw_signal(false),
+            )
+        });
+        from.move_tab(title, tabs);
+        self.windows.update(|w| {
+            w.push(DetachedWindow {
+                key,
+                title,
+                tabs,
+                pos: (at.0 + DETACH_OFFSET.0, at.1 + DETACH_OFFSET.1),
+                size,
+                hidden,
+            })
+        });
+    }
+}
+
+impl Default for DetachedTabs {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// the windows made from detached tabs. (ones that gave their tabs back stay around, hidden,
+// until the next tab is detached, since they go away as a result of their own events)
+#[component]
+pub fn DetachedTabLayer() -> impl IntoView {
+    let detached = expect_context::<DetachedTabs>();
+    provide_context(OnEveryWorkspace);
+    view! {
+        <For
+            each=detached.windows
+            key=|win| win.key
+            children=|win| view! {
+                <Window
+                    id=format!("tab-win-{}", win.key)
+                    title=win.title
+                    content=WindowContent::Moved(win.tabs)
+                    pos=WindowPos::Val(win.pos)
+                    size=create_rw_signal(win.size)
+                    hidden=win.hidden
+                    persist=false
+                    linkable=false
+                />
+            }
+        />
+    }
+}
@@ -0,0 +1,90 @@
+use leptos::*;
+use std::borrow::Cow;
+
+// a piece of a window's title, styled its own way
+#[derive(Copy, Clone, PartialEq)]
+pub enum TitlePart {
+    Text(&'static str),
+    // handwritten, like the nouns of the loading windows
+    Cursive(&'static str),
+    // drawn with the nasin nanpa font (it's still latin text underneath)
+    SitelenPona(&'static str),
+}
+
+// what's written on a window's titlebar. the footer, the desktop icons and the
+// document title are made from the same thing
+#[derive(Clone, PartialEq, Default)]
+pub struct WindowTitle {
+    icon: Option<&'static str>,
+    parts: Cow<'static, [TitlePart]>,
+}
+
+impl WindowTitle {
+    pub fn new(text: &'static str) -> Self {
+        Self::default().text(text)
+    }
+
+    // a title written out ahead of time, like a window spec's
+    pub const fn of(parts: &'static [TitlePart]) -> Self {
+        Self {
+            icon: None,
+            parts: Cow::Borrowed(parts),
+        }
+    }
+
+    pub fn text(mut self, text: &'static str) -> Self {
+        self.parts.to_mut().push(TitlePart::Text(text));
+        self
+    }
+
+    pub fn cursive(mut self, text: &'static str) -> Self {
+        self.parts.to_mut().push(TitlePart::Cursive(text));
+        self
+    }
+
+    // a small image in front of the text
+    pub fn icon(self, src: &'static str) -> Self {
+        Self {
+            icon: Some(src),
+            ..self
+        }
+    }
+
+    // the title without any styling, for places that only take text
+    pub fn plain(&self) -> String {
+        self.parts
+            .iter()
+            .map(|part| match part {
+                TitlePart::Text(text) | TitlePart::Cursive(text) | TitlePart::SitelenPona(text) => {
+                    *text
+                }
+            })
+            .collect()
+    }
+
+    pub fn view(&self) -> impl IntoView {
+        let icon = self
+            .icon
+            .map(|src| view! { <img class="title-icon" src=src alt=""/> });
+        let parts = self
+            .parts
+            .iter()
+            .map(|part| match part {
+                TitlePart::Text(text) => (*text).into_view(),
+                TitlePart::Cursive(text) => {
+                    view! { <span class="title-cursive">{*text}</span> }.into_view()
+                }
+                TitlePart::SitelenPona(text) => {
+                    view! { <span class="sitelen-pona">{*text}</span> }.into_view()
+                }
+            })
+            .collect::<Vec<_>>();
+        view! { {icon} {parts} }
+    }
+}
+
+impl From<&'static str> for WindowTitle {
+    fn from(text: &'static str) -> Self {
+        Self::new(text)
+    }
+}
@@ -1,50 +1,95 @@
 use crate::app::{
-    ExternalLink, FileLink, FileWindow, Footer, GoatCounter, LinkWindow, LoadingWindow,
-    LoadingWindowVariant, Webring, WebringWindow, Window, WindowContent, WindowPos,
+    page::{Label, Page, WindowFlags, WindowSlot, WindowSpec},
+    title::TitlePart,
+    ExternalLink, FileLink, GoatCounter, LinkWindow, LoadingWindow, LoadingWindowVariant, Webring,
+    WebringWindow, WindowContent,
 };
 use leptos::*;
 
+pub const WINDOWS: &[WindowSpec] = &[
+    WindowSpec {
+        id: "loading-win",
+        title: &[TitlePart::Text("o "), TitlePart::Cursive("pona")],
+        label: Label::Title,
+        pos: (20, 20),
+        size: (255, 255),
+        flags: WindowFlags {
+            expandable: false,
+            rainbow: true,
+            ..WindowFlags::DEFAULT
+        },
+        view: |win| view! { <LoadingWindow win=win variant=LoadingWindowVariant::TP/> }.into_view(),
+    },
+    WindowSpec {
+        id: "nasin-nanpa-link-win",
+        title: &[TitlePart::Text("nasin nanpa")],
+        label: Label::Title,
+        pos: (310, 20),
+        size: (300, 255),
+        flags: WindowFlags {
+            expandable: false,
+            rainbow: true,
+            ..WindowFlags::DEFAULT
+        },
+        view: |win| {
+            view! { <LinkWindow win=win bg_img="/assets/nasin-nanpa.png" src="/tp/nasin_nanpa"/> }
+                .into_view()
+        },
+    },
+    WindowSpec {
+        id: "kalama-sin-link-win",
+        title: &[TitlePart::Text("kalama sin")],
+        label: Label::Title,
+        pos: (20, 347),
+        size: (255, 255),
+        flags: WindowFlags {
+            expandable: false,
+            rainbow: true,
+            ..WindowFlags::DEFAULT
+        },
+        view: |win| {
+            view! { <LinkWindow win=win bg_img="/assets/kalama-sin.webp" src="/tp/kalama_sin"/> }
+                .into_view()
+        },
+    },
+    WindowSpec {
+        id: "ijo-ante-win",
+        title: &[TitlePart::Text("ijo ante")],
+        label: Label::Title,
+        pos: (310, 347),
+        size: (300, 255),
+        flags: WindowFlags {
+            scroll: true,
+            ..WindowFlags::DEFAULT
+        },
+        view: |win| view! { <IjoAnteWindow win=win/> }.into_view(),
+    },
+    WindowSpec {
+        id: "sike-pona-webring-win",
+        title: &[TitlePart::Text("sike pona")],
+        label: Label::Title,
+        pos: (20, 674),
+        size: (590, 70),
+        flags: WindowFlags {
+            expandable: false,
+            resizable: false,
+            ..WindowFlags::DEFAULT
+        },
+        view: |win| view! { <WebringWindow win=win webring=Webring::SikePona/> }.into_view(),
+    },
+];
+
 #[component]
 pub fn TokiPonaPage() -> impl IntoView {
-    let loading_hidden = create_rw_signal(false);
-    let nasin_nanpa_hidden = create_rw_signal(false);
-    let kalama_sin_hidden = create_rw_signal(false);
-    let ijo_ante_hidden = create_rw_signal(false);
-    let file_hidden = create_rw_signal(true);
-    let webring_hidden = create_rw_signal(false);
-
-    let footer_items = vec![
-        ("o pona", loading_hidden),
-        ("nasin nanpa", nasin_nanpa_hidden),
-        ("kalama sin", kalama_sin_hidden),
-        ("ijo ante", ijo_ante_hidden),
-        ("sike pona", webring_hidden),
-    ];
-    let (file_src, set_file_src) = create_signal(None);
-    let z_idx = Some(create_rw_signal(1));
-
     view! {
-        <LoadingWindow pos=WindowPos::Val((20, 20))   size=(255, 255) hidden=loading_hidden     z_idx=z_idx variant=LoadingWindowVariant::TP/>
-        <LinkWindow    pos=WindowPos::Val((310, 20))  size=(300, 255) hidden=nasin_nanpa_hidden z_idx=z_idx id="nasin-nanpa-link-win" title="nasin nanpa".to_string() bg_img="/assets/nasin-nanpa.png" src="/tp/nasin_nanpa"/>
-        <LinkWindow    pos=WindowPos::Val((20, 347))  size=(255, 255) hidden=kalama_sin_hidden  z_idx=z_idx id="kalama-sin-link-win" title="kalama sin".to_string() bg_img="/assets/kalama-sin.webp" src="/tp/kalama_sin"/>
-        <IjoAnteWindow pos=WindowPos::Val((310, 347)) size=(300, 255) hidden=ijo_ante_hidden    z_idx=z_idx file_win_src=set_file_src/>
-        <FileWindow    pos=WindowPos::Val((645, 20))  size=(700, 744) hidden=file_hidden       z_idx=z_idx src=file_src/>
-        <WebringWindow pos=WindowPos::Val((20, 674))  size=(590, 70)  hidden=webring_hidden     z_idx=z_idx webring=Webring::SikePona/>
-        <Footer items=footer_items/>
+        <Page windows=WINDOWS file_windows=((645, 20), (700, 744))/>
         <GoatCounter path="/tp"/>
     }
 }
 
 #[component]
-fn IjoAnteWindow(
-    pos: WindowPos,
-    size: (u32, u32),
-    hidden: RwSignal<bool>,
-    #[prop(default = None)] z_idx: Option<RwSignal<usize>>,
-    file_win_src: WriteSignal<Option<&'static str>>,
-) -> impl IntoView {
-    let size = create_rw_signal(size);
-    let fws = file_win_src;
+fn IjoAnteWindow(win: WindowSlot) -> impl IntoView {
+    let files = win.files;
     let active_tab = create_rw_signal("mi");
 
     let content = WindowContent::Tabs((
@@ -57,9 +102,9 @@ fn IjoAnteWindow(
                     <ul>
                         <li>"nasin sitelen tan anpa nanpa (lon sewi ↑)"</li>
                         <li>"sitelen toki pi kalama sin (lon poka ←)"</li>
-                        <li><FileLink src="https://docs.google.com/document/d/11rCtIptD3cQybXXYDv2S8ozZINYdIPx96BDM1yE2OU8/preview" display="\"kala pi alasa kala\" — toki musi" file_win_src=fws/></li>
-                        <li><FileLink src="https://docs.google.com/document/d/1FgoMDYm_R1mOZxF5fEFpc1-FzUG1lKJLWcIcyRMnzTU/preview" display="\"mu namako\" — toki musi" file_win_src=fws/></li>
-                        <li><FileLink src="https://docs.google.com/document/d/163dd0cseeR3qIpuRXlWZQClEbuLEAj3W7KRoXIo0b74/preview" display="ijo (nasa) tan anpa nanpa — toki nasa mute" file_win_src=fws/></li>
+                        <li><FileLink src="https://docs.google.com/document/d/11rCtIptD3cQybXXYDv2S8ozZINYdIPx96BDM1yE2OU8/preview" display="\"kala pi alasa kala\" — toki musi" files=files/></li>
+                        <li><FileLink src="https://docs.google.com/document/d/1FgoMDYm_R1mOZxF5fEFpc1-FzUG1lKJLWcIcyRMnzTU/preview" display="\"mu namako\" — toki musi" files=files/></li>
+                        <li><FileLink src="https://docs.google.com/document/d/163dd0cseeR3qIpuRXlWZQClEbuLEAj3W7KRoXIo0b74/preview" display="ijo (nasa) tan anpa nanpa — toki nasa mute" files=files/></li>
                     </ul>
                 </p></div> },
             ),
@@ -101,7 +146,5 @@ fn IjoAnteWindow(
         ],
     ));
 
-    view! {
-        <Window id="ijo-ante-win" title="ijo ante".to_string() content=content pos=pos size=size hidden=hidden z_idx=z_idx scroll=true/>
-    }
+    win.frame(content)
 }